@@ -10,8 +10,16 @@ function wrap(
     method = 'unknown';
   }
 
-  if (!Object.keys(error).length) {
+  // Provider errors keep message and stack as non-enumerable properties;
+  if (!error || !Object.getOwnPropertyNames(error).length) {
     error = undefined;
+  } else {
+    error = {
+      code: error.code,
+      message: error.message || String(error),
+      stack: error.stack || '',
+      data: error.data,
+    };
   }
 
   return {
@@ -64,6 +72,20 @@ async function eth_gasPrice() {
   }
 }
 
+async function eth_maxPriorityFeePerGas() {
+  const method = 'eth_maxPriorityFeePerGas';
+
+  try {
+    const maxPriorityFeePerGas = await window.ethereum.request({
+      method,
+    });
+
+    return wrap(method, true, maxPriorityFeePerGas, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
 async function eth_blockNumber() {
   const method = 'eth_blockNumber';
 
@@ -152,6 +174,146 @@ async function eth_getTransactionReceipt(
   }
 }
 
+async function eth_getLogs(filter = {}) {
+  const method = 'eth_getLogs';
+
+  try {
+    const logs = await window.ethereum.request({
+      method,
+      params: [filter],
+    });
+
+    return wrap(method, true, logs, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function eth_newFilter(filter = {}) {
+  const method = 'eth_newFilter';
+
+  try {
+    const filterId = await window.ethereum.request({
+      method,
+      params: [filter],
+    });
+
+    return wrap(method, true, filterId, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function eth_getFilterChanges(filterId = '') {
+  const method = 'eth_getFilterChanges';
+
+  try {
+    const logs = await window.ethereum.request({
+      method,
+      params: [filterId],
+    });
+
+    return wrap(method, true, logs, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function eth_uninstallFilter(filterId = '') {
+  const method = 'eth_uninstallFilter';
+
+  try {
+    const uninstalled = await window.ethereum.request({
+      method,
+      params: [filterId],
+    });
+
+    return wrap(method, true, uninstalled, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function storage_getItem(key = '') {
+  const method = 'storage_getItem';
+
+  try {
+    const value = window.localStorage.getItem(key);
+
+    return wrap(method, true, value, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function storage_setItem(key = '', value = '') {
+  const method = 'storage_setItem';
+
+  try {
+    window.localStorage.setItem(key, value);
+
+    return wrap(method, true, undefined, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function date_now() {
+  const method = 'date_now';
+
+  try {
+    return wrap(method, true, Date.now(), undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function http_get(url = '') {
+  const method = 'http_get';
+
+  try {
+    const response = await fetch(url);
+
+    if (!response.ok) {
+      throw Object.assign(
+        new Error(`${response.status} ${response.statusText}`),
+        { code: response.status }
+      );
+    }
+
+    const text = await response.text();
+
+    return wrap(method, true, text, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function http_post(url = '', body = '') {
+  const method = 'http_post';
+
+  try {
+    const response = await fetch(url, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      body,
+    });
+
+    if (!response.ok) {
+      throw Object.assign(
+        new Error(`${response.status} ${response.statusText}`),
+        { code: response.status }
+      );
+    }
+
+    const text = await response.text();
+
+    return wrap(method, true, text, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
 async function eth_sendTransaction(
   from = '',
   to = '',
@@ -198,6 +360,24 @@ async function personal_sign(message = '', address = '') {
   }
 }
 
+async function eth_signTypedData_v4(
+  address = '',
+  typedData = ''
+) {
+  const method = 'eth_signTypedData_v4';
+
+  try {
+    const signature = await window.ethereum.request({
+      method,
+      params: [address, typedData],
+    });
+
+    return wrap(method, true, signature, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
 async function wallet_switchEthereumChain(chainId = '') {
   const method = 'wallet_switchEthereumChain';
 
@@ -213,17 +393,345 @@ async function wallet_switchEthereumChain(chainId = '') {
   }
 }
 
+async function eth_getBlockByNumber(
+  block = 'latest',
+  fullTransactions = false
+) {
+  const method = 'eth_getBlockByNumber';
+
+  try {
+    const blockByNumber = await window.ethereum.request({
+      method,
+      params: [block, fullTransactions],
+    });
+
+    return wrap(method, true, blockByNumber, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function eth_getBlockByHash(
+  blockHash = '',
+  fullTransactions = false
+) {
+  const method = 'eth_getBlockByHash';
+
+  try {
+    const blockByHash = await window.ethereum.request({
+      method,
+      params: [blockHash, fullTransactions],
+    });
+
+    return wrap(method, true, blockByHash, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function eth_getBlockReceipts(block = 'latest') {
+  const method = 'eth_getBlockReceipts';
+
+  try {
+    const receipts = await window.ethereum.request({
+      method,
+      params: [block],
+    });
+
+    return wrap(method, true, receipts, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function eth_getTransactionCount(
+  address = '',
+  block = 'latest'
+) {
+  const method = 'eth_getTransactionCount';
+
+  try {
+    const transactionCount = await window.ethereum.request({
+      method,
+      params: [address, block],
+    });
+
+    return wrap(method, true, transactionCount, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function eth_getCode(address = '', block = 'latest') {
+  const method = 'eth_getCode';
+
+  try {
+    const code = await window.ethereum.request({
+      method,
+      params: [address, block],
+    });
+
+    return wrap(method, true, code, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function eth_getStorageAt(
+  address = '',
+  slot = '',
+  block = 'latest'
+) {
+  const method = 'eth_getStorageAt';
+
+  try {
+    const storage = await window.ethereum.request({
+      method,
+      params: [address, slot, block],
+    });
+
+    return wrap(method, true, storage, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function eth_getTransactionByHash(
+  transactionHash = ''
+) {
+  const method = 'eth_getTransactionByHash';
+
+  try {
+    const transaction = await window.ethereum.request({
+      method,
+      params: [transactionHash],
+    });
+
+    return wrap(method, true, transaction, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function eth_getTransactionByBlockNumberAndIndex(
+  block = 'latest',
+  index = '0x0'
+) {
+  const method = 'eth_getTransactionByBlockNumberAndIndex';
+
+  try {
+    const transaction = await window.ethereum.request({
+      method,
+      params: [block, index],
+    });
+
+    return wrap(method, true, transaction, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function eth_estimateGas(
+  from = '',
+  to = '',
+  value = '',
+  calldata = ''
+) {
+  const method = 'eth_estimateGas';
+
+  try {
+    const gas = await window.ethereum.request({
+      method,
+      params: [
+        {
+          ...(from && { from }),
+          to,
+          ...(value && { value }),
+          ...(calldata && { data: calldata }),
+        },
+      ],
+    });
+
+    return wrap(method, true, gas, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function wallet_addEthereumChain(chain = {}) {
+  const method = 'wallet_addEthereumChain';
+
+  try {
+    await window.ethereum.request({
+      method,
+      params: [chain],
+    });
+
+    return wrap(method, true, undefined, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function wallet_watchAsset(asset = {}) {
+  const method = 'wallet_watchAsset';
+
+  try {
+    const added = await window.ethereum.request({
+      method,
+      params: asset,
+    });
+
+    return wrap(method, true, added, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function wallet_requestPermissions(permissions = {}) {
+  const method = 'wallet_requestPermissions';
+
+  try {
+    const granted = await window.ethereum.request({
+      method,
+      params: [permissions],
+    });
+
+    return wrap(method, true, granted, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function wallet_getPermissions() {
+  const method = 'wallet_getPermissions';
+
+  try {
+    const permissions = await window.ethereum.request({
+      method,
+    });
+
+    return wrap(method, true, permissions, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function wallet_revokePermissions(permissions = {}) {
+  const method = 'wallet_revokePermissions';
+
+  try {
+    await window.ethereum.request({
+      method,
+      params: [permissions],
+    });
+
+    return wrap(method, true, undefined, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function wallet_getCapabilities(address = '', chainIds = []) {
+  const method = 'wallet_getCapabilities';
+
+  try {
+    const capabilities = await window.ethereum.request({
+      method,
+      params: chainIds.length ? [address, chainIds] : [address],
+    });
+
+    return wrap(method, true, capabilities, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function wallet_sendCalls(calls = {}) {
+  const method = 'wallet_sendCalls';
+
+  try {
+    const result = await window.ethereum.request({
+      method,
+      params: [calls],
+    });
+
+    return wrap(method, true, result, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function wallet_getCallsStatus(id = '') {
+  const method = 'wallet_getCallsStatus';
+
+  try {
+    const status = await window.ethereum.request({
+      method,
+      params: [id],
+    });
+
+    return wrap(method, true, status, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
+async function wallet_showCallsStatus(id = '') {
+  const method = 'wallet_showCallsStatus';
+
+  try {
+    await window.ethereum.request({
+      method,
+      params: [id],
+    });
+
+    return wrap(method, true, undefined, undefined);
+  } catch (error) {
+    return wrap(method, false, undefined, error);
+  }
+}
+
 window['dxEvalProvider'] = {
   wrap,
   eth_accounts,
   eth_requestAccounts,
   eth_gasPrice,
+  eth_maxPriorityFeePerGas,
   eth_blockNumber,
   eth_chainId,
   eth_getBalance,
   eth_call,
   eth_getTransactionReceipt,
+  eth_getLogs,
+  eth_newFilter,
+  eth_getFilterChanges,
+  eth_uninstallFilter,
   eth_sendTransaction,
   personal_sign,
+  eth_signTypedData_v4,
   wallet_switchEthereumChain,
+  storage_getItem,
+  storage_setItem,
+  date_now,
+  http_get,
+  http_post,
+  eth_getBlockByNumber,
+  eth_getBlockByHash,
+  eth_getBlockReceipts,
+  eth_getTransactionCount,
+  eth_getCode,
+  eth_getStorageAt,
+  eth_getTransactionByHash,
+  eth_getTransactionByBlockNumberAndIndex,
+  eth_estimateGas,
+  wallet_addEthereumChain,
+  wallet_watchAsset,
+  wallet_requestPermissions,
+  wallet_getPermissions,
+  wallet_revokePermissions,
+  wallet_getCapabilities,
+  wallet_sendCalls,
+  wallet_getCallsStatus,
+  wallet_showCallsStatus,
 };
@@ -0,0 +1,5 @@
+use alloy_sol_types::sol;
+
+sol! {
+    error OffchainLookup(address sender, string[] urls, bytes callData, bytes4 callbackFunction, bytes extraData);
+}
@@ -0,0 +1,17 @@
+use alloy_sol_types::sol;
+
+sol! {
+    interface ENSRegistry {
+        function resolver(bytes32 node) external view returns (address);
+    }
+
+    interface ENSResolver {
+        function addr(bytes32 node) external view returns (address);
+        function name(bytes32 node) external view returns (string memory);
+        function text(bytes32 node, string calldata key) external view returns (string memory);
+    }
+
+    interface ENSExtendedResolver {
+        function resolve(bytes memory name, bytes memory data) external view returns (bytes memory);
+    }
+}
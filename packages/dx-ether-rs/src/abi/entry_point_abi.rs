@@ -0,0 +1,20 @@
+use alloy_sol_types::sol;
+
+sol! {
+    struct PackedUserOperation {
+        address sender;
+        uint256 nonce;
+        bytes initCode;
+        bytes callData;
+        bytes32 accountGasLimits;
+        uint256 preVerificationGas;
+        bytes32 gasFees;
+        bytes paymasterAndData;
+        bytes signature;
+    }
+
+    interface EntryPoint {
+        function getNonce(address sender, uint192 key) external view returns (uint256 nonce);
+        function getUserOpHash(PackedUserOperation calldata userOp) external view returns (bytes32);
+    }
+}
@@ -0,0 +1,18 @@
+use alloy_sol_types::sol;
+
+sol! {
+    interface ERC1155 {
+        event TransferSingle(address indexed operator, address indexed from, address indexed to, uint256 id, uint256 value);
+        event TransferBatch(address indexed operator, address indexed from, address indexed to, uint256[] ids, uint256[] values);
+        event ApprovalForAll(address indexed account, address indexed operator, bool approved);
+        event URI(string value, uint256 indexed id);
+
+        function uri(uint256 id) external view returns (string);
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+        function balanceOfBatch(address[] accounts, uint256[] ids) external view returns (uint256[]);
+        function isApprovedForAll(address account, address operator) external view returns (bool);
+        function setApprovalForAll(address operator, bool approved) external;
+        function safeTransferFrom(address from, address to, uint256 id, uint256 value, bytes data) external;
+        function safeBatchTransferFrom(address from, address to, uint256[] ids, uint256[] values, bytes data) external;
+    }
+}
@@ -0,0 +1,7 @@
+use alloy_sol_types::sol;
+
+sol! {
+    interface ERC1271 {
+        function isValidSignature(bytes32 hash, bytes memory signature) external view returns (bytes4 magicValue);
+    }
+}
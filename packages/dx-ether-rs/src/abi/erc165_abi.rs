@@ -0,0 +1,7 @@
+use alloy_sol_types::sol;
+
+sol! {
+    interface ERC165 {
+        function supportsInterface(bytes4 interfaceId) external view returns (bool);
+    }
+}
@@ -0,0 +1,24 @@
+use alloy_sol_types::sol;
+
+sol! {
+    interface ERC20 {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Approval(address indexed owner, address indexed spender, uint256 value);
+
+        function name() external view returns (string);
+        function symbol() external view returns (string);
+        function decimals() external view returns (uint8);
+        function totalSupply() external view returns (uint256);
+        function balanceOf(address owner) external view returns (uint256);
+        function allowance(address owner, address spender) external view returns (uint256);
+        function transfer(address to, uint256 value) external returns (bool);
+        function approve(address spender, uint256 value) external returns (bool);
+        function transferFrom(address from, address to, uint256 value) external returns (bool);
+    }
+
+    // Early tokens (e.g. MKR) return `bytes32` metadata;
+    interface ERC20Bytes32 {
+        function name() external view returns (bytes32);
+        function symbol() external view returns (bytes32);
+    }
+}
@@ -0,0 +1,9 @@
+pub mod ccip_read_abi;
+pub mod ens_abi;
+pub mod entry_point_abi;
+pub mod erc1155_abi;
+pub mod erc1271_abi;
+pub mod erc165_abi;
+pub mod erc20_abi;
+pub mod erc721_abi;
+pub mod multicall3_abi;
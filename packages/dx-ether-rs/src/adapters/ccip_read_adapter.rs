@@ -0,0 +1,463 @@
+use alloy_primitives::{Address, Bytes, hex};
+use alloy_sol_types::{SolError, SolValue};
+
+use crate::{
+    abi::ccip_read_abi::OffchainLookup,
+    adapters::{ether_adapter::EtherAdapter, ether_hash_adapter::EtherHashAdapter},
+    errors::{error::Error, ether_error::EtherError},
+    ports::{
+        ether::{ether_call_port::EtherCallPort, ether_chain_id_port::EtherChainIdPort},
+        ether_hash_port::EtherHashPort,
+        http_get_port::HttpGetPort,
+        http_post_port::HttpPostPort,
+    },
+    types::ccip_read_types::CcipReadOptions,
+};
+
+/*
+    `eth_call` following EIP-3668 `OffchainLookup` reverts through the provider HTTP ports.
+*/
+#[derive(Clone)]
+pub struct CcipReadAdapter<P = EtherAdapter> {
+    provider: P,
+    options: CcipReadOptions,
+    ether_hash: EtherHashAdapter,
+}
+
+impl Default for CcipReadAdapter<EtherAdapter> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CcipReadAdapter<EtherAdapter> {
+    pub fn new() -> Self {
+        Self::with_provider(EtherAdapter::new())
+    }
+}
+
+impl<P> CcipReadAdapter<P> {
+    pub fn with_provider(provider: P) -> Self {
+        Self {
+            provider,
+            options: CcipReadOptions::default(),
+            ether_hash: EtherHashAdapter::new(),
+        }
+    }
+
+    pub fn options(mut self, options: CcipReadOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl<P> CcipReadAdapter<P>
+where
+    P: HttpGetPort<Output = String, Error = Error> + HttpPostPort<Output = String, Error = Error>,
+{
+    fn offchain_lookup(&self, error: &Error) -> Option<OffchainLookup> {
+        if !self.options.enabled {
+            return None;
+        }
+
+        let data = EtherError::from_error(error)?.revert_data()?;
+
+        OffchainLookup::abi_decode(&data).ok()
+    }
+
+    fn allowed(&self, url: &str) -> bool {
+        match &self.options.allowed_urls {
+            Some(r) => r.iter().any(|i| url.starts_with(i.as_str())),
+            _ => url.starts_with("https://"),
+        }
+    }
+
+    /*
+        Gateways are tried in order: `{data}` URLs with GET, others with a JSON POST.
+        Client errors (4xx) stop the lookup, other failures move on to the next URL.
+    */
+    async fn fetch(&self, lookup: &OffchainLookup) -> Result<Bytes, Error> {
+        let sender = hex::encode_prefixed(lookup.sender);
+        let data = hex::encode_prefixed(&lookup.callData);
+
+        let mut errors = Vec::new();
+
+        for url in lookup.urls.iter().filter(|r| self.allowed(r)) {
+            let url = url.replace("{sender}", &sender);
+
+            let response = match url.contains("{data}") {
+                true => self.provider.http_get(&url.replace("{data}", &data)).await,
+                false => {
+                    let body = serde_json::json!({ "data": data, "sender": sender });
+
+                    self.provider.http_post(&url, &body.to_string()).await
+                }
+            };
+
+            let error = match response {
+                Ok(r) => {
+                    let response = serde_json::from_str::<serde_json::Value>(&r)?;
+
+                    let data = response["data"]
+                        .as_str()
+                        .ok_or("[ccip-read]: gateway response has no data")?;
+
+                    return Ok(Bytes::from(self.ether_hash.decode_to_bytes(data)?));
+                }
+                Err(e) => e,
+            };
+
+            let code = EtherError::from_error(&error).and_then(|r| r.code);
+
+            if code.is_some_and(|r| (400..500).contains(&r)) {
+                return Err(error);
+            }
+
+            errors.push(error.to_string());
+        }
+
+        match errors.is_empty() {
+            true => Err("[ccip-read]: no allowed gateway url".into()),
+            false => Err(format!("[ccip-read]: {}", errors.join(", ")).into()),
+        }
+    }
+}
+
+impl<P> EtherCallPort for CcipReadAdapter<P>
+where
+    P: EtherCallPort<Output = String, Error = Error>
+        + HttpGetPort<Output = String, Error = Error>
+        + HttpPostPort<Output = String, Error = Error>,
+{
+    type Output = String;
+
+    type Error = Error;
+
+    /*
+        The callback is called on the same contract, the sender of a lookup must be that contract.
+    */
+    async fn eth_call(
+        &self,
+        address: Address,
+        calldata: &str,
+        block: Option<String>,
+    ) -> Result<Self::Output, Self::Error> {
+        let mut calldata = calldata.to_string();
+        let mut lookups = 0;
+
+        loop {
+            let error = match self
+                .provider
+                .eth_call(address, &calldata, block.clone())
+                .await
+            {
+                Ok(r) => return Ok(r),
+                Err(e) => e,
+            };
+
+            let Some(lookup) = self.offchain_lookup(&error) else {
+                return Err(error);
+            };
+
+            if lookups >= self.options.max_lookups {
+                return Err(format!("[eth_call]: more than {} offchain lookups", lookups).into());
+            }
+
+            lookups += 1;
+
+            if lookup.sender != address {
+                return Err("[eth_call]: offchain lookup sender mismatch".into());
+            }
+
+            let response = self.fetch(&lookup).await?;
+
+            calldata = self.ether_hash.encode_to_string(
+                &[
+                    lookup.callbackFunction.as_slice(),
+                    &(response, lookup.extraData).abi_encode_params(),
+                ]
+                .concat(),
+            )?;
+        }
+    }
+}
+
+/*
+    Passed through, adapters reading over CCIP-Read also need the chain and plain HTTP.
+*/
+impl<P> EtherChainIdPort for CcipReadAdapter<P>
+where
+    P: EtherChainIdPort<Output = String, Error = Error>,
+{
+    type Output = String;
+
+    type Error = Error;
+
+    async fn eth_chain_id(&self) -> Result<Self::Output, Self::Error> {
+        self.provider.eth_chain_id().await
+    }
+}
+
+impl<P> HttpGetPort for CcipReadAdapter<P>
+where
+    P: HttpGetPort<Output = String, Error = Error>,
+{
+    type Output = String;
+
+    type Error = Error;
+
+    async fn http_get(&self, url: &str) -> Result<Self::Output, Self::Error> {
+        self.provider.http_get(url).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::block_on;
+    use alloy_primitives::{FixedBytes, address};
+    use std::{collections::HashMap, sync::Mutex};
+
+    const RESOLVER: Address = address!("0xc1735677a60884abbcf72295e88d47764beda282");
+    const CALLBACK: [u8; 4] = [0x12, 0x34, 0x56, 0x78];
+
+    /*
+        Contract reverting with `lookup` until its callback is called, gateways answer
+        with a body or fail with an HTTP status.
+    */
+    struct StubGateway {
+        lookup: OffchainLookup,
+        callback: Option<String>,
+        responses: HashMap<String, Result<String, i64>>,
+        calls: Mutex<Vec<String>>,
+        requests: Mutex<Vec<(String, Option<String>)>>,
+    }
+
+    impl StubGateway {
+        fn new(sender: Address, urls: &[&str]) -> Self {
+            Self {
+                lookup: OffchainLookup {
+                    sender,
+                    urls: urls.iter().map(|r| r.to_string()).collect(),
+                    callData: Bytes::from(vec![0xab, 0xcd]),
+                    callbackFunction: FixedBytes(CALLBACK),
+                    extraData: Bytes::from(vec![0xee]),
+                },
+                callback: Some(String::from("0x2a")),
+                responses: HashMap::new(),
+                calls: Mutex::new(Vec::new()),
+                requests: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn with(mut self, url: &str, response: Result<&str, i64>) -> Self {
+            self.responses
+                .insert(url.to_string(), response.map(str::to_string));
+            self
+        }
+
+        fn respond(&self, method: &str, url: &str) -> Result<String, Error> {
+            match self.responses.get(url) {
+                Some(Ok(r)) => Ok(r.clone()),
+                Some(Err(r)) => Err(EtherError {
+                    method: method.to_string(),
+                    code: Some(*r),
+                    message: format!("status {}", r),
+                    ..Default::default()
+                }
+                .into()),
+                None => Err(format!("[{}]: {} unreachable", method, url).into()),
+            }
+        }
+
+        fn calls(&self) -> Vec<String> {
+            self.calls.lock().unwrap().clone()
+        }
+
+        fn requests(&self) -> Vec<(String, Option<String>)> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    impl EtherCallPort for StubGateway {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_call(
+            &self,
+            _address: Address,
+            calldata: &str,
+            _block: Option<String>,
+        ) -> Result<Self::Output, Self::Error> {
+            self.calls.lock().unwrap().push(calldata.to_string());
+
+            if calldata.starts_with(&hex::encode_prefixed(CALLBACK))
+                && let Some(result) = &self.callback
+            {
+                return Ok(result.clone());
+            }
+
+            Err(EtherError {
+                method: String::from("eth_call"),
+                code: Some(3),
+                message: String::from("execution reverted"),
+                data: Some(serde_json::json!(hex::encode_prefixed(
+                    self.lookup.abi_encode()
+                ))),
+                ..Default::default()
+            }
+            .into())
+        }
+    }
+
+    impl HttpGetPort for StubGateway {
+        type Output = String;
+        type Error = Error;
+
+        async fn http_get(&self, url: &str) -> Result<Self::Output, Self::Error> {
+            self.requests.lock().unwrap().push((url.to_string(), None));
+            self.respond("http_get", url)
+        }
+    }
+
+    impl HttpPostPort for StubGateway {
+        type Output = String;
+        type Error = Error;
+
+        async fn http_post(&self, url: &str, body: &str) -> Result<Self::Output, Self::Error> {
+            self.requests
+                .lock()
+                .unwrap()
+                .push((url.to_string(), Some(body.to_string())));
+            self.respond("http_post", url)
+        }
+    }
+
+    fn call(adapter: &CcipReadAdapter<StubGateway>) -> Result<String, Error> {
+        block_on(adapter.eth_call(RESOLVER, "0x01", None))
+    }
+
+    #[test]
+    fn follows_offchain_lookup() {
+        let gateway = StubGateway::new(RESOLVER, &["https://gw.test/{sender}/{data}.json"]).with(
+            &format!(
+                "https://gw.test/{}/0xabcd.json",
+                hex::encode_prefixed(RESOLVER)
+            ),
+            Ok(r#"{"data":"0xbeef"}"#),
+        );
+        let adapter = CcipReadAdapter::with_provider(gateway);
+
+        assert_eq!(call(&adapter).unwrap(), "0x2a");
+
+        let callback = [
+            CALLBACK.as_slice(),
+            &(Bytes::from(vec![0xbe, 0xef]), Bytes::from(vec![0xee])).abi_encode_params(),
+        ]
+        .concat();
+
+        assert_eq!(
+            adapter.provider.calls(),
+            vec![String::from("0x01"), hex::encode_prefixed(callback)]
+        );
+    }
+
+    #[test]
+    fn posts_to_gateways_without_data_template() {
+        let gateway = StubGateway::new(RESOLVER, &["https://gw.test/"])
+            .with("https://gw.test/", Ok(r#"{"data":"0x"}"#));
+        let adapter = CcipReadAdapter::with_provider(gateway);
+
+        assert!(call(&adapter).is_ok());
+
+        let body = serde_json::json!({
+            "data": "0xabcd",
+            "sender": hex::encode_prefixed(RESOLVER),
+        });
+
+        assert_eq!(
+            adapter.provider.requests(),
+            vec![(String::from("https://gw.test/"), Some(body.to_string()))]
+        );
+    }
+
+    #[test]
+    fn rejects_sender_mismatch() {
+        let sender = address!("0x0000000000000000000000000000000000000001");
+        let gateway = StubGateway::new(sender, &["https://gw.test/"]);
+        let adapter = CcipReadAdapter::with_provider(gateway);
+
+        let error = call(&adapter).unwrap_err();
+
+        assert!(error.to_string().contains("sender mismatch"));
+        assert!(adapter.provider.requests().is_empty());
+    }
+
+    #[test]
+    fn stops_on_client_error() {
+        let gateway = StubGateway::new(RESOLVER, &["https://first.test/", "https://second.test/"])
+            .with("https://first.test/", Err(404))
+            .with("https://second.test/", Ok(r#"{"data":"0x"}"#));
+        let adapter = CcipReadAdapter::with_provider(gateway);
+
+        assert_eq!(
+            EtherError::from_error(&call(&adapter).unwrap_err()).and_then(|r| r.code),
+            Some(404)
+        );
+        assert_eq!(adapter.provider.requests().len(), 1);
+    }
+
+    #[test]
+    fn moves_on_after_server_error() {
+        let gateway = StubGateway::new(RESOLVER, &["https://first.test/", "https://second.test/"])
+            .with("https://first.test/", Err(502))
+            .with("https://second.test/", Ok(r#"{"data":"0x"}"#));
+        let adapter = CcipReadAdapter::with_provider(gateway);
+
+        assert_eq!(call(&adapter).unwrap(), "0x2a");
+        assert_eq!(adapter.provider.requests().len(), 2);
+    }
+
+    #[test]
+    fn skips_disallowed_urls() {
+        let gateway = StubGateway::new(RESOLVER, &["http://plain.test/", "https://other.test/"]);
+        let adapter = CcipReadAdapter::with_provider(gateway).options(CcipReadOptions {
+            allowed_urls: Some(vec![String::from("https://gw.test/")]),
+            ..Default::default()
+        });
+
+        let error = call(&adapter).unwrap_err();
+
+        assert!(error.to_string().contains("no allowed gateway url"));
+        assert!(adapter.provider.requests().is_empty());
+    }
+
+    #[test]
+    fn limits_lookups() {
+        let mut gateway = StubGateway::new(RESOLVER, &["https://gw.test/"])
+            .with("https://gw.test/", Ok(r#"{"data":"0x"}"#));
+        gateway.callback = None;
+
+        let adapter = CcipReadAdapter::with_provider(gateway).options(CcipReadOptions {
+            max_lookups: 2,
+            ..Default::default()
+        });
+
+        let error = call(&adapter).unwrap_err();
+
+        assert!(error.to_string().contains("more than 2 offchain lookups"));
+        assert_eq!(adapter.provider.calls().len(), 3);
+    }
+
+    #[test]
+    fn returns_revert_when_disabled() {
+        let gateway = StubGateway::new(RESOLVER, &["https://gw.test/"]);
+        let adapter = CcipReadAdapter::with_provider(gateway).options(CcipReadOptions {
+            enabled: false,
+            ..Default::default()
+        });
+
+        assert!(EtherError::from_error(&call(&adapter).unwrap_err()).is_some());
+        assert!(adapter.provider.requests().is_empty());
+    }
+}
@@ -0,0 +1,103 @@
+use alloy_primitives::Address;
+use alloy_sol_types::{SolCall, SolInterface};
+use std::marker::PhantomData;
+
+use crate::{
+    adapters::{ether_adapter::EtherAdapter, ether_hash_adapter::EtherHashAdapter},
+    errors::error::Error,
+    ports::{
+        contract_port::ContractPort,
+        ether::{
+            ether_call_port::EtherCallPort, ether_send_transaction_port::EtherSendTransactionPort,
+        },
+        ether_hash_port::EtherHashPort,
+    },
+    types::contract_types::ContractTransactionOptions,
+};
+
+/*
+    Contract binding over an alloy `sol!` interface, e.g. `Contract::<WETH_ABI::WETH_ABICalls>`.
+    Calls outside of the interface are rejected, the call data and return data are ABI encoded.
+*/
+pub struct ContractAdapter<I, P = EtherAdapter> {
+    address: Address,
+    provider: P,
+    ether_hash: EtherHashAdapter,
+    interface: PhantomData<I>,
+}
+
+pub type Contract<I> = ContractAdapter<I, EtherAdapter>;
+
+impl<I: SolInterface> ContractAdapter<I, EtherAdapter> {
+    pub fn new(address: Address) -> Self {
+        Self::with_provider(address, EtherAdapter::new())
+    }
+}
+
+impl<I: SolInterface, P> ContractAdapter<I, P> {
+    pub fn with_provider(address: Address, provider: P) -> Self {
+        Self {
+            address,
+            provider,
+            ether_hash: EtherHashAdapter::new(),
+            interface: PhantomData,
+        }
+    }
+
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    pub fn encode<C: SolCall>(&self, call: C) -> Result<String, Error> {
+        if !I::valid_selector(C::SELECTOR) {
+            return Err(format!("[{}]: not a call of {}", C::SIGNATURE, I::NAME).into());
+        }
+
+        self.ether_hash.encode_to_string(&call.abi_encode())
+    }
+}
+
+impl<I, P> ContractPort<I> for ContractAdapter<I, P>
+where
+    I: SolInterface,
+    P: EtherCallPort<Output = String, Error = Error>
+        + EtherSendTransactionPort<Output = String, Error = Error>,
+{
+    type Error = Error;
+
+    async fn read<C: SolCall>(&self, call: C, block: Option<String>) -> Result<C::Return, Error> {
+        let calldata = self.encode(call)?;
+
+        let data = self
+            .provider
+            .eth_call(self.address, &calldata, block)
+            .await?;
+
+        let bytes = self.ether_hash.decode_to_bytes(&data)?;
+
+        Ok(C::abi_decode_returns(&bytes)?)
+    }
+
+    async fn write<C: SolCall>(
+        &self,
+        call: C,
+        options: &ContractTransactionOptions,
+    ) -> Result<String, Error> {
+        let calldata = self.encode(call)?;
+
+        self.provider
+            .eth_send_transaction(
+                options.from,
+                self.address,
+                options.value.clone(),
+                Some(calldata),
+                options.gas.clone(),
+                options.gas_price.clone(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,705 @@
+use alloy_primitives::{Address, B256, Bytes, FixedBytes, U256, hex, keccak256};
+use alloy_sol_types::SolCall;
+
+use crate::{
+    abi::{
+        ens_abi::{ENSExtendedResolver, ENSRegistry, ENSResolver},
+        erc165_abi::ERC165,
+        erc721_abi::ERC721,
+        erc1155_abi::ERC1155,
+    },
+    adapters::{
+        ccip_read_adapter::CcipReadAdapter,
+        erc165_adapter::is_interface_unsupported,
+        ether_address_adapter::parse_address,
+        ether_chain_adapter::{ChainId, known_chain, update_chain},
+        ether_hash_adapter::EtherHashAdapter,
+        nft_metadata_adapter::{NftGateways, NftMetadataAdapter},
+    },
+    constants::ens_constants::ENS_EXTENDED_RESOLVER_INTERFACE_ID,
+    errors::{ens_error::EnsError, error::Error},
+    ports::{
+        ens_port::EnsPort,
+        ether::{ether_call_port::EtherCallPort, ether_chain_id_port::EtherChainIdPort},
+        ether_hash_port::EtherHashPort,
+        http_get_port::HttpGetPort,
+        nft_metadata_port::NftMetadataPort,
+    },
+    types::ccip_read_types::CcipReadOptions,
+};
+
+/*
+    Register the ENS registry of a known chain, e.g. a local deployment on a devnet.
+*/
+pub fn set_ens_registry_address(chain_id: u64, address: &str) -> Result<(), Error> {
+    update_chain(ChainId(chain_id), |r| {
+        r.ens_registry = Some(address.to_string())
+    })
+}
+
+pub fn ens_registry_address(chain_id: u64) -> Option<String> {
+    known_chain(ChainId(chain_id))?.ens_registry
+}
+
+/*
+    Canonical form of a name before hashing: lowercase ASCII limited to `a-z0-9-_$`,
+    underscores only leading and no `--` at label positions 3-4. These names are already
+    ENSIP-15 normalized; non-ASCII names (emoji, other scripts) need the full ENSIP-15
+    rules and are rejected instead of being hashed unnormalized.
+*/
+pub fn canonicalize_name(name: &str) -> Result<String, Error> {
+    let invalid = |reason: &str| EnsError::InvalidName {
+        name: name.to_string(),
+        reason: reason.to_string(),
+    };
+
+    let mut labels = Vec::new();
+
+    for label in name.trim().split('.') {
+        if let Some(r) = label.chars().find(|r| !r.is_ascii()) {
+            return Err(invalid(&format!(
+                "non-ASCII character {:?} needs ENSIP-15 normalization",
+                r
+            ))
+            .into());
+        }
+
+        let label = label.to_ascii_lowercase();
+
+        if label.is_empty() {
+            return Err(invalid("empty label").into());
+        }
+
+        if let Some(r) = label
+            .chars()
+            .find(|r| !(r.is_ascii_lowercase() || r.is_ascii_digit() || "-_$".contains(*r)))
+        {
+            return Err(invalid(&format!("disallowed character {:?}", r)).into());
+        }
+
+        if label.trim_start_matches('_').contains('_') {
+            return Err(invalid("underscore allowed only at the start").into());
+        }
+
+        if label.get(2..4) == Some("--") {
+            return Err(invalid("hyphens at positions 3 and 4").into());
+        }
+
+        labels.push(label);
+    }
+
+    Ok(labels.join("."))
+}
+
+/*
+    EIP-137 namehash of an already normalized name.
+*/
+pub fn namehash(name: &str) -> B256 {
+    let mut node = B256::ZERO;
+
+    if name.is_empty() {
+        return node;
+    }
+
+    for label in name.rsplit('.') {
+        node = keccak256([node.as_slice(), keccak256(label.as_bytes()).as_slice()].concat());
+    }
+
+    node
+}
+
+/*
+    DNS wire format used by ENSIP-10 `resolve(bytes,bytes)`.
+*/
+pub fn dns_encode(name: &str) -> Result<Bytes, Error> {
+    let mut encoded = Vec::with_capacity(name.len() + 2);
+
+    for label in name.split('.').filter(|r| !r.is_empty()) {
+        let length = u8::try_from(label.len()).map_err(|_| EnsError::InvalidName {
+            name: name.to_string(),
+            reason: String::from("label longer than 255 bytes"),
+        })?;
+
+        encoded.push(length);
+        encoded.extend_from_slice(label.as_bytes());
+    }
+
+    encoded.push(0);
+
+    Ok(Bytes::from(encoded))
+}
+
+pub struct EnsAdapter<P = CcipReadAdapter> {
+    provider: P,
+    registry: Option<String>,
+    gateways: NftGateways,
+    ether_hash: EtherHashAdapter,
+}
+
+impl Default for EnsAdapter<CcipReadAdapter> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EnsAdapter<CcipReadAdapter> {
+    pub fn new() -> Self {
+        Self::with_provider(CcipReadAdapter::new())
+    }
+
+    /*
+        Offchain (CCIP-Read) resolvers with custom gateway limits.
+    */
+    pub fn with_ccip_read(options: CcipReadOptions) -> Self {
+        Self::with_provider(CcipReadAdapter::new().options(options))
+    }
+}
+
+impl<P> EnsAdapter<P> {
+    pub fn with_provider(provider: P) -> Self {
+        Self {
+            provider,
+            registry: None,
+            gateways: NftGateways::default(),
+            ether_hash: EtherHashAdapter::new(),
+        }
+    }
+
+    /*
+        Fixed registry address, otherwise it is picked by the wallet chain id.
+    */
+    pub fn registry(mut self, address: &str) -> Self {
+        self.registry = Some(address.to_string());
+        self
+    }
+
+    /*
+        Gateways for `ipfs://` and `ar://` avatars.
+    */
+    pub fn gateways(mut self, gateways: NftGateways) -> Self {
+        self.gateways = gateways;
+        self
+    }
+}
+
+impl<P> EnsAdapter<P>
+where
+    P: EtherCallPort<Output = String, Error = Error>
+        + EtherChainIdPort<Output = String, Error = Error>
+        + HttpGetPort<Output = String, Error = Error>
+        + Clone,
+{
+    async fn registry_address(&self) -> Result<Address, Error> {
+        if let Some(address) = &self.registry {
+            return parse_address(address);
+        }
+
+        let chain_id = self.provider.eth_chain_id().await?.parse::<u64>()?;
+
+        match ens_registry_address(chain_id) {
+            Some(r) => parse_address(&r),
+            None => Err(EnsError::UnsupportedChain(chain_id).into()),
+        }
+    }
+
+    async fn call<C: SolCall>(&self, address: Address, call: C) -> Result<C::Return, Error> {
+        let calldata = self.ether_hash.encode_to_string(&call.abi_encode())?;
+
+        let data = self.provider.eth_call(address, &calldata, None).await?;
+
+        let bytes = self.ether_hash.decode_to_bytes(&data)?;
+
+        Ok(C::abi_decode_returns(&bytes)?)
+    }
+
+    async fn supports_interface(
+        &self,
+        address: Address,
+        interface_id: [u8; 4],
+    ) -> Result<bool, Error> {
+        let call = ERC165::supportsInterfaceCall {
+            interfaceId: FixedBytes(interface_id),
+        };
+
+        match self.call(address, call).await {
+            Err(e) if is_interface_unsupported(&e) => Ok(false),
+            r => r,
+        }
+    }
+
+    /*
+        Walk up the name until a resolver is set, ENSIP-10 wildcard resolution.
+        Returns the resolver and whether it was set on the name itself.
+    */
+    async fn find_resolver(&self, name: &str) -> Result<Option<(Address, bool)>, Error> {
+        let registry = self.registry_address().await?;
+        let mut current = name;
+
+        loop {
+            let call = ENSRegistry::resolverCall {
+                node: namehash(current),
+            };
+
+            let resolver = self.call(registry, call).await?;
+
+            if !resolver.is_zero() {
+                return Ok(Some((resolver, current == name)));
+            }
+
+            match current.split_once('.') {
+                Some((_, parent)) => current = parent,
+                _ => return Ok(None),
+            }
+        }
+    }
+
+    /*
+        Extended resolvers get the record call wrapped into `resolve(dnsName, data)`,
+        wildcard matches without ENSIP-10 support have no records.
+    */
+    async fn resolve_record<C: SolCall>(
+        &self,
+        name: &str,
+        call: C,
+    ) -> Result<Option<C::Return>, Error> {
+        let Some((resolver, exact)) = self.find_resolver(name).await? else {
+            return Ok(None);
+        };
+
+        if self
+            .supports_interface(resolver, ENS_EXTENDED_RESOLVER_INTERFACE_ID)
+            .await?
+        {
+            let resolve = ENSExtendedResolver::resolveCall {
+                name: dns_encode(name)?,
+                data: Bytes::from(call.abi_encode()),
+            };
+
+            let data = self.call(resolver, resolve).await?;
+
+            return Ok(Some(C::abi_decode_returns(&data)?));
+        }
+
+        match exact {
+            true => Ok(Some(self.call(resolver, call).await?)),
+            false => Ok(None),
+        }
+    }
+
+    /*
+        ENSIP-12 NFT avatar `eip155:<chain>/<erc721|erc1155>:<contract>/<id>`, owned by `owner`.
+        Only NFTs on the provider chain can be read.
+    */
+    async fn nft_avatar(
+        &self,
+        value: &str,
+        owner: Option<Address>,
+    ) -> Result<Option<String>, Error> {
+        let Some((chain, asset)) = value.split_once('/') else {
+            return Ok(None);
+        };
+
+        let chain_id = self.provider.eth_chain_id().await?.parse::<u64>()?;
+
+        if chain.strip_prefix("eip155:").map(str::parse::<u64>) != Some(Ok(chain_id)) {
+            return Ok(None);
+        }
+
+        let Some((standard, reference)) = asset.split_once(':') else {
+            return Ok(None);
+        };
+
+        let Some((contract, token_id)) = reference.split_once('/') else {
+            return Ok(None);
+        };
+
+        let contract = parse_address(contract)?;
+        let token_id = U256::from_str_radix(token_id, 10)?;
+
+        let (uri, substitute) = match standard.to_lowercase().as_str() {
+            "erc721" => {
+                let holder = self
+                    .call(contract, ERC721::ownerOfCall { tokenId: token_id })
+                    .await?;
+
+                if owner.is_some_and(|r| r != holder) {
+                    return Ok(None);
+                }
+
+                let call = ERC721::tokenURICall { tokenId: token_id };
+
+                (self.call(contract, call).await?, None)
+            }
+            "erc1155" => {
+                if let Some(owner) = owner {
+                    let call = ERC1155::balanceOfCall {
+                        account: owner,
+                        id: token_id,
+                    };
+
+                    if self.call(contract, call).await?.is_zero() {
+                        return Ok(None);
+                    }
+                }
+
+                let call = ERC1155::uriCall { id: token_id };
+
+                (self.call(contract, call).await?, Some(token_id))
+            }
+            _ => return Ok(None),
+        };
+
+        let metadata =
+            NftMetadataAdapter::with_provider(self.provider.clone(), self.gateways.clone())
+                .resolve(&uri, substitute)
+                .await?;
+
+        Ok(metadata.image_src)
+    }
+}
+
+impl<P> EnsPort for EnsAdapter<P>
+where
+    P: EtherCallPort<Output = String, Error = Error>
+        + EtherChainIdPort<Output = String, Error = Error>
+        + HttpGetPort<Output = String, Error = Error>
+        + Clone,
+{
+    type Error = Error;
+
+    async fn resolve_name(&self, name: &str) -> Result<Option<Address>, Self::Error> {
+        let name = canonicalize_name(name)?;
+
+        let call = ENSResolver::addrCall {
+            node: namehash(&name),
+        };
+
+        let address = self.resolve_record(&name, call).await?;
+
+        Ok(address.filter(|r| !r.is_zero()))
+    }
+
+    /*
+        Reverse record, only trusted when the name is canonical and resolves back to the address.
+    */
+    async fn lookup_address(&self, address: &Address) -> Result<Option<String>, Self::Error> {
+        let reverse = format!("{}.addr.reverse", hex::encode(address));
+
+        let call = ENSResolver::nameCall {
+            node: namehash(&reverse),
+        };
+
+        let Some(name) = self
+            .resolve_record(&reverse, call)
+            .await?
+            .filter(|r| !r.is_empty())
+        else {
+            return Ok(None);
+        };
+
+        if canonicalize_name(&name).ok().as_ref() != Some(&name) {
+            return Ok(None);
+        }
+
+        match self.resolve_name(&name).await? {
+            Some(r) if r == *address => Ok(Some(name)),
+            _ => Ok(None),
+        }
+    }
+
+    async fn text(&self, name: &str, key: &str) -> Result<Option<String>, Self::Error> {
+        let name = canonicalize_name(name)?;
+
+        let call = ENSResolver::textCall {
+            node: namehash(&name),
+            key: key.to_string(),
+        };
+
+        let text = self.resolve_record(&name, call).await?;
+
+        Ok(text.filter(|r| !r.is_empty()))
+    }
+
+    /*
+        ENSIP-12 avatar as an `img` src: http(s), data, ipfs, ar or an owned NFT.
+    */
+    async fn avatar(&self, name: &str) -> Result<Option<String>, Self::Error> {
+        let Some(avatar) = self.text(name, "avatar").await? else {
+            return Ok(None);
+        };
+
+        let avatar = avatar.trim();
+
+        if avatar.starts_with("eip155:") {
+            let owner = self.resolve_name(name).await?;
+
+            return self.nft_avatar(avatar, owner).await;
+        }
+
+        match avatar.starts_with("data:") {
+            true => Ok(Some(avatar.to_string())),
+            false => Ok(Some(self.gateways.resolve_url(avatar))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::block_on;
+    use alloy_primitives::{address, b256};
+    use alloy_sol_types::SolValue;
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    const REGISTRY: Address = address!("0x00000000000c2e074ec69a0dfb2997ba6c7d2e1e");
+    const RESOLVER: Address = address!("0x231b0ee14048e9dccd1d247744d114a4eb5e8e63");
+    const OWNER: Address = address!("0xb8c2c29ee19d8307cb7255e1cd9cbde883a267d5");
+    const NFT: Address = address!("0xb47e3cd837ddf8e4c57f05d70ab865de6e193bbb");
+
+    /*
+        Registry and resolvers stand-in: calls without a response return empty data,
+        like a contract without the function.
+    */
+    #[derive(Clone, Default)]
+    struct StubRegistry {
+        chain_id: String,
+        responses: HashMap<(Address, String), String>,
+        calls: Arc<Mutex<Vec<(Address, String)>>>,
+    }
+
+    impl StubRegistry {
+        fn new(chain_id: &str) -> Self {
+            Self {
+                chain_id: chain_id.to_string(),
+                ..Self::default()
+            }
+        }
+
+        fn with<C: SolCall>(mut self, to: Address, call: C, output: Vec<u8>) -> Self {
+            let calldata = format!("0x{}", hex::encode(call.abi_encode()));
+
+            self.responses
+                .insert((to, calldata), format!("0x{}", hex::encode(output)));
+            self
+        }
+
+        fn resolver(self, name: &str, resolver: Address) -> Self {
+            let call = ENSRegistry::resolverCall {
+                node: namehash(name),
+            };
+
+            self.with(REGISTRY, call, resolver.abi_encode())
+        }
+
+        fn called(&self, to: Address) -> bool {
+            self.calls.lock().unwrap().iter().any(|(r, _)| *r == to)
+        }
+    }
+
+    impl EtherCallPort for StubRegistry {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_call(
+            &self,
+            address: Address,
+            calldata: &str,
+            _block: Option<String>,
+        ) -> Result<Self::Output, Self::Error> {
+            let key = (address, calldata.to_string());
+
+            self.calls.lock().unwrap().push(key.clone());
+
+            Ok(self
+                .responses
+                .get(&key)
+                .cloned()
+                .unwrap_or_else(|| String::from("0x")))
+        }
+    }
+
+    impl EtherChainIdPort for StubRegistry {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_chain_id(&self) -> Result<Self::Output, Self::Error> {
+            Ok(self.chain_id.clone())
+        }
+    }
+
+    impl HttpGetPort for StubRegistry {
+        type Output = String;
+        type Error = Error;
+
+        async fn http_get(&self, url: &str) -> Result<Self::Output, Self::Error> {
+            Err(format!("[http get]: {} unreachable", url).into())
+        }
+    }
+
+    fn adapter(registry: StubRegistry) -> EnsAdapter<StubRegistry> {
+        EnsAdapter::with_provider(registry).registry(&REGISTRY.to_string())
+    }
+
+    #[test]
+    fn hashes_eip137_vectors() {
+        assert_eq!(namehash(""), B256::ZERO);
+        assert_eq!(
+            namehash("eth"),
+            b256!("0x93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae")
+        );
+        assert_eq!(
+            namehash("foo.eth"),
+            b256!("0xde9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f")
+        );
+    }
+
+    #[test]
+    fn encodes_dns_wire_format() {
+        assert_eq!(
+            dns_encode("foo.eth").unwrap(),
+            Bytes::from(hex::decode("03666f6f0365746800").unwrap())
+        );
+        assert_eq!(dns_encode("").unwrap(), Bytes::from(vec![0]));
+        assert!(dns_encode(&"a".repeat(256)).is_err());
+    }
+
+    #[test]
+    fn canonicalizes_names() {
+        assert_eq!(canonicalize_name(" Nick.ETH ").unwrap(), "nick.eth");
+        assert_eq!(canonicalize_name("__ab$.eth").unwrap(), "__ab$.eth");
+
+        for name in [
+            "nick..eth",
+            "a_b.eth",
+            "ab--cd.eth",
+            "a b.eth",
+            "a\u{0}.eth",
+            "caf\u{e9}.eth",
+            "\u{2764}\u{fe0f}.eth",
+            "\u{430}pple.eth",
+        ] {
+            assert!(canonicalize_name(name).is_err(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn resolves_exact_resolver() {
+        let registry = StubRegistry::new("1").resolver("nick.eth", RESOLVER).with(
+            RESOLVER,
+            ENSResolver::addrCall {
+                node: namehash("nick.eth"),
+            },
+            OWNER.abi_encode(),
+        );
+
+        assert_eq!(
+            block_on(adapter(registry).resolve_name("Nick.eth")).unwrap(),
+            Some(OWNER)
+        );
+    }
+
+    #[test]
+    fn resolves_wildcard_through_extended_resolver() {
+        let record = ENSResolver::addrCall {
+            node: namehash("sub.nick.eth"),
+        };
+        let resolve = ENSExtendedResolver::resolveCall {
+            name: dns_encode("sub.nick.eth").unwrap(),
+            data: Bytes::from(record.abi_encode()),
+        };
+        let supports = ERC165::supportsInterfaceCall {
+            interfaceId: FixedBytes(ENS_EXTENDED_RESOLVER_INTERFACE_ID),
+        };
+
+        let registry = StubRegistry::new("1")
+            .resolver("sub.nick.eth", Address::ZERO)
+            .resolver("nick.eth", RESOLVER)
+            .with(RESOLVER, supports, true.abi_encode())
+            .with(
+                RESOLVER,
+                resolve,
+                Bytes::from(OWNER.abi_encode()).abi_encode(),
+            );
+
+        assert_eq!(
+            block_on(adapter(registry).resolve_name("sub.nick.eth")).unwrap(),
+            Some(OWNER)
+        );
+    }
+
+    #[test]
+    fn ignores_wildcard_without_extended_resolver() {
+        let registry = StubRegistry::new("1")
+            .resolver("sub.nick.eth", Address::ZERO)
+            .resolver("nick.eth", RESOLVER)
+            .with(
+                RESOLVER,
+                ENSResolver::addrCall {
+                    node: namehash("sub.nick.eth"),
+                },
+                OWNER.abi_encode(),
+            );
+
+        assert_eq!(
+            block_on(adapter(registry).resolve_name("sub.nick.eth")).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn rejects_unverified_reverse_record() {
+        let reverse = format!("{}.addr.reverse", hex::encode(OWNER));
+
+        let registry = StubRegistry::new("1")
+            .resolver(&reverse, RESOLVER)
+            .resolver("nick.eth", RESOLVER)
+            .with(
+                RESOLVER,
+                ENSResolver::nameCall {
+                    node: namehash(&reverse),
+                },
+                String::from("nick.eth").abi_encode(),
+            )
+            .with(
+                RESOLVER,
+                ENSResolver::addrCall {
+                    node: namehash("nick.eth"),
+                },
+                NFT.abi_encode(),
+            );
+
+        assert_eq!(
+            block_on(adapter(registry).lookup_address(&OWNER)).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn rejects_nft_avatar_on_another_chain() {
+        let registry = StubRegistry::new("1")
+            .resolver("nick.eth", RESOLVER)
+            .with(
+                RESOLVER,
+                ENSResolver::textCall {
+                    node: namehash("nick.eth"),
+                    key: String::from("avatar"),
+                },
+                format!("eip155:5/erc721:{}/1", NFT).abi_encode(),
+            )
+            .with(
+                RESOLVER,
+                ENSResolver::addrCall {
+                    node: namehash("nick.eth"),
+                },
+                OWNER.abi_encode(),
+            );
+
+        let ens = adapter(registry.clone());
+
+        assert_eq!(block_on(ens.avatar("nick.eth")).unwrap(), None);
+        assert!(!registry.called(NFT));
+    }
+}
@@ -0,0 +1,142 @@
+use alloy_primitives::{Address, Bytes, U256};
+
+use crate::{
+    abi::erc1155_abi::ERC1155,
+    adapters::{contract_adapter::ContractAdapter, ether_adapter::EtherAdapter},
+    errors::error::Error,
+    ports::{
+        contract_port::ContractPort,
+        erc1155_port::Erc1155Port,
+        ether::{
+            ether_call_port::EtherCallPort, ether_send_transaction_port::EtherSendTransactionPort,
+        },
+    },
+    types::contract_types::ContractTransactionOptions,
+};
+
+pub struct Erc1155Adapter<P = EtherAdapter> {
+    contract: ContractAdapter<ERC1155::ERC1155Calls, P>,
+}
+
+impl Erc1155Adapter<EtherAdapter> {
+    pub fn new(token: Address) -> Self {
+        Self::with_provider(token, EtherAdapter::new())
+    }
+}
+
+impl<P> Erc1155Adapter<P> {
+    pub fn with_provider(token: Address, provider: P) -> Self {
+        Self {
+            contract: ContractAdapter::with_provider(token, provider),
+        }
+    }
+
+    fn options(&self, from: Address) -> ContractTransactionOptions {
+        ContractTransactionOptions {
+            from,
+            ..Default::default()
+        }
+    }
+}
+
+impl<P> Erc1155Port for Erc1155Adapter<P>
+where
+    P: EtherCallPort<Output = String, Error = Error>
+        + EtherSendTransactionPort<Output = String, Error = Error>,
+{
+    type Error = Error;
+
+    async fn balance_of(&self, owner: Address, id: U256) -> Result<U256, Self::Error> {
+        let call = ERC1155::balanceOfCall { account: owner, id };
+
+        self.contract.read(call, None).await
+    }
+
+    async fn balance_of_batch(
+        &self,
+        owners: &[Address],
+        ids: &[U256],
+    ) -> Result<Vec<U256>, Self::Error> {
+        if owners.len() != ids.len() {
+            return Err("[balanceOfBatch]: owners and ids length mismatch".into());
+        }
+
+        let call = ERC1155::balanceOfBatchCall {
+            accounts: owners.to_vec(),
+            ids: ids.to_vec(),
+        };
+
+        self.contract.read(call, None).await
+    }
+
+    async fn uri(&self, id: U256) -> Result<String, Self::Error> {
+        self.contract.read(ERC1155::uriCall { id }, None).await
+    }
+
+    async fn is_approved_for_all(
+        &self,
+        owner: Address,
+        operator: Address,
+    ) -> Result<bool, Self::Error> {
+        let call = ERC1155::isApprovedForAllCall {
+            account: owner,
+            operator,
+        };
+
+        self.contract.read(call, None).await
+    }
+
+    async fn set_approval_for_all(
+        &self,
+        from: Address,
+        operator: Address,
+        approved: bool,
+    ) -> Result<String, Self::Error> {
+        let call = ERC1155::setApprovalForAllCall { operator, approved };
+
+        self.contract.write(call, &self.options(from)).await
+    }
+
+    /*
+        `from` signs the transaction, `owner` holds the tokens; they differ for approved operators.
+    */
+    async fn safe_transfer_from(
+        &self,
+        from: Address,
+        owner: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+        data: Vec<u8>,
+    ) -> Result<String, Self::Error> {
+        let call = ERC1155::safeTransferFromCall {
+            from: owner,
+            to,
+            id,
+            value: amount,
+            data: Bytes::from(data),
+        };
+
+        self.contract.write(call, &self.options(from)).await
+    }
+
+    async fn safe_batch_transfer_from(
+        &self,
+        from: Address,
+        owner: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+        data: Vec<u8>,
+    ) -> Result<String, Self::Error> {
+        let call = ERC1155::safeBatchTransferFromCall {
+            from: owner,
+            to,
+            ids,
+            values: amounts,
+            data: Bytes::from(data),
+        };
+
+        self.contract.write(call, &self.options(from)).await
+    }
+}
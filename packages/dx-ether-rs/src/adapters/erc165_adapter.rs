@@ -0,0 +1,91 @@
+use alloy_primitives::{Address, FixedBytes};
+
+use crate::{
+    abi::erc165_abi::ERC165,
+    adapters::{contract_adapter::ContractAdapter, ether_adapter::EtherAdapter},
+    errors::{error::Error, ether_error::EtherError},
+    ports::{
+        contract_port::ContractPort,
+        erc165_port::Erc165Port,
+        ether::{
+            ether_call_port::EtherCallPort, ether_send_transaction_port::EtherSendTransactionPort,
+        },
+    },
+    types::nft_types::NftStandard,
+};
+
+pub const ERC165_INTERFACE_ID: [u8; 4] = [0x01, 0xff, 0xc9, 0xa7];
+pub const ERC721_INTERFACE_ID: [u8; 4] = [0x80, 0xac, 0x58, 0xcd];
+pub const ERC721_METADATA_INTERFACE_ID: [u8; 4] = [0x5b, 0x5e, 0x13, 0x9f];
+pub const ERC1155_INTERFACE_ID: [u8; 4] = [0xd9, 0xb6, 0x7a, 0x26];
+pub const ERC1155_METADATA_URI_INTERFACE_ID: [u8; 4] = [0x0e, 0x89, 0x34, 0x1c];
+
+/*
+    A revert or a return that does not decode as `bool` means the contract has no ERC-165,
+    anything else (transport, wallet, rate limit) is a real failure.
+*/
+pub fn is_interface_unsupported(error: &Error) -> bool {
+    error.is::<alloy_sol_types::Error>()
+        || EtherError::from_error(error).is_some_and(|r| r.is_execution_reverted())
+}
+
+pub struct Erc165Adapter<P = EtherAdapter> {
+    contract: ContractAdapter<ERC165::ERC165Calls, P>,
+}
+
+impl Erc165Adapter<EtherAdapter> {
+    pub fn new(address: Address) -> Self {
+        Self::with_provider(address, EtherAdapter::new())
+    }
+}
+
+impl<P> Erc165Adapter<P> {
+    pub fn with_provider(address: Address, provider: P) -> Self {
+        Self {
+            contract: ContractAdapter::with_provider(address, provider),
+        }
+    }
+}
+
+impl<P> Erc165Port for Erc165Adapter<P>
+where
+    P: EtherCallPort<Output = String, Error = Error>
+        + EtherSendTransactionPort<Output = String, Error = Error>,
+{
+    type Error = Error;
+
+    /*
+        Contracts without ERC-165 revert or return nothing, which counts as not supported.
+    */
+    async fn supports_interface(&self, interface_id: [u8; 4]) -> Result<bool, Self::Error> {
+        let call = ERC165::supportsInterfaceCall {
+            interfaceId: FixedBytes(interface_id),
+        };
+
+        match self.contract.read(call, None).await {
+            Err(e) if is_interface_unsupported(&e) => Ok(false),
+            r => r,
+        }
+    }
+
+    /*
+        ERC-165 requires `0xffffffff` to be rejected, otherwise the answers can not be trusted.
+    */
+    async fn detect_standard(&self) -> Result<NftStandard, Self::Error> {
+        if !self.supports_interface(ERC165_INTERFACE_ID).await?
+            || self.supports_interface([0xff; 4]).await?
+        {
+            return Ok(NftStandard::Unknown);
+        }
+
+        if self.supports_interface(ERC721_INTERFACE_ID).await? {
+            return Ok(NftStandard::Erc721);
+        }
+
+        if self.supports_interface(ERC1155_INTERFACE_ID).await? {
+            return Ok(NftStandard::Erc1155);
+        }
+
+        Ok(NftStandard::Unknown)
+    }
+}
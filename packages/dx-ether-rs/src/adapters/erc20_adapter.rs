@@ -0,0 +1,274 @@
+use alloy_primitives::{Address, B256, U256};
+use alloy_sol_types::SolCall;
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+};
+
+use crate::{
+    abi::erc20_abi::{ERC20, ERC20Bytes32},
+    adapters::{ether_adapter::EtherAdapter, ether_hash_adapter::EtherHashAdapter},
+    errors::{error::Error, ether_error::EtherError},
+    ports::{
+        erc20_port::Erc20Port,
+        ether::{
+            ether_call_port::EtherCallPort, ether_chain_id_port::EtherChainIdPort,
+            ether_send_transaction_port::EtherSendTransactionPort,
+        },
+        ether_hash_port::EtherHashPort,
+    },
+    types::erc20_types::{Erc20Amount, Erc20Metadata},
+};
+
+/*
+    Token metadata never changes, so it is fetched once per chain and token address.
+    Decimals are cached on their own, `name` and `symbol` are optional and may revert.
+*/
+static ERC20_METADATA: LazyLock<Mutex<HashMap<String, Erc20Metadata>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+static ERC20_DECIMALS: LazyLock<Mutex<HashMap<String, u8>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+pub struct Erc20Adapter<P = EtherAdapter> {
+    token: Address,
+    provider: P,
+    ether_hash: EtherHashAdapter,
+}
+
+impl Erc20Adapter<EtherAdapter> {
+    pub fn new(token: Address) -> Self {
+        Self::with_provider(token, EtherAdapter::new())
+    }
+}
+
+impl<P> Erc20Adapter<P>
+where
+    P: EtherCallPort<Output = String, Error = Error>
+        + EtherChainIdPort<Output = String, Error = Error>
+        + EtherSendTransactionPort<Output = String, Error = Error>,
+{
+    pub fn with_provider(token: Address, provider: P) -> Self {
+        Self {
+            token,
+            provider,
+            ether_hash: EtherHashAdapter::new(),
+        }
+    }
+
+    pub fn token(&self) -> Address {
+        self.token
+    }
+
+    async fn cache_key(&self) -> Result<String, Error> {
+        let chain_id = self.provider.eth_chain_id().await?.parse::<u64>()?;
+
+        Ok(format!("{}:{}", chain_id, self.token))
+    }
+
+    async fn call<C: SolCall>(&self, call: C) -> Result<Vec<u8>, Error> {
+        let calldata = self.ether_hash.encode_to_string(&call.abi_encode())?;
+
+        let data = self.provider.eth_call(self.token, &calldata, None).await?;
+
+        self.ether_hash.decode_to_bytes(&data)
+    }
+
+    async fn send<C: SolCall>(&self, from: Address, call: C) -> Result<String, Error> {
+        let calldata = self.ether_hash.encode_to_string(&call.abi_encode())?;
+
+        self.provider
+            .eth_send_transaction(from, self.token, None, Some(calldata), None, None)
+            .await
+    }
+
+    /*
+        Metadata strings are ABI `string` for most tokens and `bytes32` for early ones.
+    */
+    fn decode_text<C: SolCall<Return = String>, B: SolCall<Return = B256>>(
+        &self,
+        bytes: &[u8],
+    ) -> Result<String, Error> {
+        if let Ok(r) = C::abi_decode_returns(bytes) {
+            return Ok(r);
+        }
+
+        let word = B::abi_decode_returns(bytes)?;
+
+        Ok(String::from_utf8_lossy(word.as_slice())
+            .trim_end_matches('\0')
+            .to_string())
+    }
+
+    /*
+        Empty for a token that reverts or returns garbage on an optional string getter,
+        transport and wallet errors are still returned.
+    */
+    async fn optional_text<C: SolCall<Return = String>, B: SolCall<Return = B256>>(
+        &self,
+        call: C,
+    ) -> Result<String, Error> {
+        match self.call(call).await {
+            Ok(r) => Ok(self.decode_text::<C, B>(&r).unwrap_or_default()),
+            Err(e) if EtherError::from_error(&e).is_some_and(|r| r.is_execution_reverted()) => {
+                Ok(String::new())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /*
+        `decimals` is optional in the standard and some tokens return it as `uint256`.
+    */
+    fn decode_decimals(&self, bytes: &[u8]) -> Result<u8, Error> {
+        let word = bytes.get(..32).ok_or("[decimals]: empty return data")?;
+
+        Ok(u8::try_from(U256::from_be_slice(word))?)
+    }
+
+    fn decode_amount(&self, bytes: &[u8], decimals: u8) -> Result<Erc20Amount, Error> {
+        Ok(Erc20Amount::new(
+            ERC20::balanceOfCall::abi_decode_returns(bytes)?,
+            decimals,
+        ))
+    }
+}
+
+impl<P> Erc20Port for Erc20Adapter<P>
+where
+    P: EtherCallPort<Output = String, Error = Error>
+        + EtherChainIdPort<Output = String, Error = Error>
+        + EtherSendTransactionPort<Output = String, Error = Error>,
+{
+    type Error = Error;
+
+    async fn metadata(&self) -> Result<Erc20Metadata, Self::Error> {
+        let key = self.cache_key().await?;
+
+        if let Some(r) = ERC20_METADATA
+            .lock()
+            .ok()
+            .and_then(|r| r.get(&key).cloned())
+        {
+            return Ok(r);
+        }
+
+        let metadata = Erc20Metadata {
+            name: self
+                .optional_text::<ERC20::nameCall, ERC20Bytes32::nameCall>(ERC20::nameCall {})
+                .await?,
+            symbol: self
+                .optional_text::<ERC20::symbolCall, ERC20Bytes32::symbolCall>(ERC20::symbolCall {})
+                .await?,
+            decimals: self.decimals().await?,
+        };
+
+        if let Ok(mut r) = ERC20_METADATA.lock() {
+            r.insert(key, metadata.clone());
+        }
+
+        Ok(metadata)
+    }
+
+    async fn symbol(&self) -> Result<String, Self::Error> {
+        let bytes = self.call(ERC20::symbolCall {}).await?;
+
+        self.decode_text::<ERC20::symbolCall, ERC20Bytes32::symbolCall>(&bytes)
+    }
+
+    async fn decimals(&self) -> Result<u8, Self::Error> {
+        let key = self.cache_key().await?;
+
+        if let Some(r) = ERC20_DECIMALS
+            .lock()
+            .ok()
+            .and_then(|r| r.get(&key).copied())
+        {
+            return Ok(r);
+        }
+
+        let bytes = self.call(ERC20::decimalsCall {}).await?;
+        let decimals = self.decode_decimals(&bytes)?;
+
+        if let Ok(mut r) = ERC20_DECIMALS.lock() {
+            r.insert(key, decimals);
+        }
+
+        Ok(decimals)
+    }
+
+    async fn total_supply(&self) -> Result<Erc20Amount, Self::Error> {
+        let decimals = self.decimals().await?;
+        let bytes = self.call(ERC20::totalSupplyCall {}).await?;
+
+        self.decode_amount(&bytes, decimals)
+    }
+
+    async fn balance_of(&self, owner: Address) -> Result<Erc20Amount, Self::Error> {
+        let decimals = self.decimals().await?;
+
+        let bytes = self.call(ERC20::balanceOfCall { owner }).await?;
+
+        self.decode_amount(&bytes, decimals)
+    }
+
+    async fn allowance(
+        &self,
+        owner: Address,
+        spender: Address,
+    ) -> Result<Erc20Amount, Self::Error> {
+        let decimals = self.decimals().await?;
+
+        let bytes = self.call(ERC20::allowanceCall { owner, spender }).await?;
+
+        self.decode_amount(&bytes, decimals)
+    }
+
+    /*
+        Writes return the transaction hash and never decode the `bool` result,
+        so tokens without a return value (e.g. USDT) work the same way.
+    */
+    async fn transfer(
+        &self,
+        from: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<String, Self::Error> {
+        self.send(from, ERC20::transferCall { to, value: amount })
+            .await
+    }
+
+    async fn approve(
+        &self,
+        from: Address,
+        spender: Address,
+        amount: U256,
+    ) -> Result<String, Self::Error> {
+        self.send(
+            from,
+            ERC20::approveCall {
+                spender,
+                value: amount,
+            },
+        )
+        .await
+    }
+
+    async fn transfer_from(
+        &self,
+        from: Address,
+        owner: Address,
+        to: Address,
+        amount: U256,
+    ) -> Result<String, Self::Error> {
+        self.send(
+            from,
+            ERC20::transferFromCall {
+                from: owner,
+                to,
+                value: amount,
+            },
+        )
+        .await
+    }
+}
@@ -0,0 +1,108 @@
+use alloy_primitives::{Address, Bytes, U256};
+
+use crate::{
+    abi::erc721_abi::ERC721,
+    adapters::{contract_adapter::ContractAdapter, ether_adapter::EtherAdapter},
+    errors::error::Error,
+    ports::{
+        contract_port::ContractPort,
+        erc721_port::Erc721Port,
+        ether::{
+            ether_call_port::EtherCallPort, ether_send_transaction_port::EtherSendTransactionPort,
+        },
+    },
+    types::contract_types::ContractTransactionOptions,
+};
+
+pub struct Erc721Adapter<P = EtherAdapter> {
+    contract: ContractAdapter<ERC721::ERC721Calls, P>,
+}
+
+impl Erc721Adapter<EtherAdapter> {
+    pub fn new(token: Address) -> Self {
+        Self::with_provider(token, EtherAdapter::new())
+    }
+}
+
+impl<P> Erc721Adapter<P> {
+    pub fn with_provider(token: Address, provider: P) -> Self {
+        Self {
+            contract: ContractAdapter::with_provider(token, provider),
+        }
+    }
+
+    fn options(&self, from: Address) -> ContractTransactionOptions {
+        ContractTransactionOptions {
+            from,
+            ..Default::default()
+        }
+    }
+}
+
+impl<P> Erc721Port for Erc721Adapter<P>
+where
+    P: EtherCallPort<Output = String, Error = Error>
+        + EtherSendTransactionPort<Output = String, Error = Error>,
+{
+    type Error = Error;
+
+    async fn owner_of(&self, token_id: U256) -> Result<Address, Self::Error> {
+        self.contract
+            .read(ERC721::ownerOfCall { tokenId: token_id }, None)
+            .await
+    }
+
+    async fn balance_of(&self, owner: Address) -> Result<U256, Self::Error> {
+        let call = ERC721::balanceOfCall { owner };
+
+        self.contract.read(call, None).await
+    }
+
+    async fn token_uri(&self, token_id: U256) -> Result<String, Self::Error> {
+        self.contract
+            .read(ERC721::tokenURICall { tokenId: token_id }, None)
+            .await
+    }
+
+    async fn is_approved_for_all(
+        &self,
+        owner: Address,
+        operator: Address,
+    ) -> Result<bool, Self::Error> {
+        let call = ERC721::isApprovedForAllCall { owner, operator };
+
+        self.contract.read(call, None).await
+    }
+
+    async fn set_approval_for_all(
+        &self,
+        from: Address,
+        operator: Address,
+        approved: bool,
+    ) -> Result<String, Self::Error> {
+        let call = ERC721::setApprovalForAllCall { operator, approved };
+
+        self.contract.write(call, &self.options(from)).await
+    }
+
+    /*
+        `from` signs the transaction, `owner` holds the tokens; they differ for approved operators.
+    */
+    async fn safe_transfer_from(
+        &self,
+        from: Address,
+        owner: Address,
+        to: Address,
+        token_id: U256,
+        data: Vec<u8>,
+    ) -> Result<String, Self::Error> {
+        let call = ERC721::safeTransferFromCall {
+            from: owner,
+            to,
+            tokenId: token_id,
+            data: Bytes::from(data),
+        };
+
+        self.contract.write(call, &self.options(from)).await
+    }
+}
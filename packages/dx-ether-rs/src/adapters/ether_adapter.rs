@@ -1,24 +1,70 @@
+use alloy_dyn_abi::TypedData;
+use alloy_primitives::{Address, B256, Bytes, Log, hex};
+use alloy_sol_types::{SolEvent, SolEventInterface};
 use dioxus::document;
 use gloo_timers::future::sleep;
-use std::time::Duration;
+use std::{collections::HashMap, str::FromStr, time::Duration};
 
 use crate::{
-    adapters::ether_hash_adapter::EtherHashAdapter,
+    adapters::{
+        erc20_adapter::Erc20Adapter,
+        ether_address_adapter::EtherAddressAdapter,
+        ether_chain_adapter::{ChainId, known_chain},
+        ether_hash_adapter::EtherHashAdapter,
+        ether_signature_adapter::EtherSignatureAdapter,
+    },
     errors::{error::Error, ether_error::EtherError},
     ports::{
+        erc20_port::Erc20Port,
         ether::{
             ether_accounts_port::EtherAccountsPort, ether_block_number_port::EtherBlockNumberPort,
             ether_call_port::EtherCallPort, ether_chain_id_port::EtherChainIdPort,
-            ether_error_port::EtherErrorPort, ether_gas_price_port::EtherGasPricePort,
-            ether_get_balance_port::EtherGetBalancerPort,
+            ether_error_port::EtherErrorPort, ether_estimate_gas_port::EtherEstimateGasPort,
+            ether_gas_price_port::EtherGasPricePort, ether_get_balance_port::EtherGetBalancerPort,
+            ether_get_block_by_hash_port::EtherGetBlockByHashPort,
+            ether_get_block_by_number_port::EtherGetBlockByNumberPort,
+            ether_get_block_receipts_port::EtherGetBlockReceiptsPort,
+            ether_get_code_port::EtherGetCodePort,
+            ether_get_filter_changes_port::EtherGetFilterChangesPort,
+            ether_get_logs_port::EtherGetLogsPort,
+            ether_get_storage_at_port::EtherGetStorageAtPort,
+            ether_get_transaction_by_block_number_and_index_port::EtherGetTransactionByBlockNumberAndIndexPort,
+            ether_get_transaction_by_hash_port::EtherGetTransactionByHashPort,
+            ether_get_transaction_count_port::EtherGetTransactionCountPort,
             ether_get_transaction_receipt_port::EtherGetTransactionReceiptPort,
+            ether_max_priority_fee_per_gas_port::EtherMaxPriorityFeePerGasPort,
+            ether_new_filter_port::EtherNewFilterPort,
             ether_request_accounts_port::EtherRequestAccountsPort,
             ether_send_transaction_port::EtherSendTransactionPort,
+            ether_sign_typed_data_port::EtherSignTypedDataPort,
+            ether_uninstall_filter_port::EtherUninstallFilterPort,
             personal_sign_port::PersonalSignPort,
+            wallet_add_ethereum_chain_port::WalletAddEthereumChainPort,
+            wallet_get_calls_status_port::WalletGetCallsStatusPort,
+            wallet_get_capabilities_port::WalletGetCapabilitiesPort,
+            wallet_get_permissions_port::WalletGetPermissionsPort,
+            wallet_request_permissions_port::WalletRequestPermissionsPort,
+            wallet_revoke_permissions_port::WalletRevokePermissionsPort,
+            wallet_send_calls_port::WalletSendCallsPort,
+            wallet_show_calls_status_port::WalletShowCallsStatusPort,
             wallet_switch_ethereum_chain_port::WalletSwitchEthereumChainPort,
+            wallet_watch_asset_port::WalletWatchAssetPort,
         },
+        ether_address_port::EtherAddressPort,
         ether_hash_port::EtherHashPort,
+        ether_signature_port::EtherSignaturePort,
+        http_get_port::HttpGetPort,
+        http_post_port::HttpPostPort,
+    },
+    types::ether_calls_types::{
+        EtherCallsStatus, EtherCapabilities, EtherSendCalls, EtherSendCallsResult,
     },
+    types::ether_chain_types::EtherChainParameters,
+    types::ether_log_types::EtherLogFilter,
+    types::ether_permission_types::{EtherPermission, encode_permissions},
+    types::ether_typed_data_types::EtherTypedDataSignature,
+    types::ether_watch_asset_types::{EtherWatchAsset, EtherWatchAssetResult},
+    types::personal_sign_types::{EtherPersonalSignMessage, EtherPersonalSignature},
 };
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -29,19 +75,37 @@ struct EtherWrapper<D: Default, E = Error> {
     pub error: Option<E>,
 }
 
+#[derive(Clone)]
 pub struct EtherAdapter {
+    ether_address: EtherAddressAdapter,
     ether_hash: EtherHashAdapter,
     key: String,
 }
 
+impl Default for EtherAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EtherAdapter {
     pub fn new() -> Self {
         Self {
+            ether_address: EtherAddressAdapter::new(),
             ether_hash: EtherHashAdapter::new(),
             key: String::from("window.dxEvalProvider"),
         }
     }
 
+    /*
+        Addresses are EIP-55 by default, EIP-1191 checksums (Rootstock) are opt-in with the
+        connected chain id, e.g. `EtherAdapter::new().chain_id(chain_id)`.
+    */
+    pub fn chain_id(mut self, chain_id: ChainId) -> Self {
+        self.ether_address = EtherAddressAdapter::for_chain(chain_id);
+        self
+    }
+
     pub async fn wait_js_scripts(&self) -> Result<(), Error> {
         loop {
             let mut eval = document::eval(&format!("await dioxus.send(typeof {})", self.key));
@@ -58,6 +122,69 @@ impl EtherAdapter {
         Ok(())
     }
 
+    /*
+        Quantities accept tags, `0x` hex or decimal numbers; decimals are sent as hex.
+    */
+    fn encode_quantity(&self, method: &str, value: &str) -> Result<String, Error> {
+        match value {
+            "latest" | "earliest" | "pending" | "safe" | "finalized" => Ok(value.to_string()),
+            r if r.parse::<u64>().is_ok() => self.ether_hash.encode_to_number(r),
+            r => self.encode_hex(method, r),
+        }
+    }
+
+    /*
+        `0x` hex values (filter ids, slots) are checked before they are quoted into the eval.
+    */
+    fn encode_hex(&self, method: &str, value: &str) -> Result<String, Error> {
+        match value.strip_prefix("0x") {
+            Some(r) if !r.is_empty() && r.chars().all(|i| i.is_ascii_hexdigit()) => {
+                Ok(value.to_string())
+            }
+            _ => Err(format!("[{}]: invalid hex value {}", method, value).into()),
+        }
+    }
+
+    pub async fn storage_get(&self, key: &str) -> Result<Option<String>, Error> {
+        let key = serde_json::to_string(key)?;
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.storage_getItem({key}))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Option<String>, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
+
+    pub async fn storage_set(&self, key: &str, value: &str) -> Result<(), Error> {
+        let key = serde_json::to_string(key)?;
+        let value = serde_json::to_string(value)?;
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.storage_setItem({key}, {value}))",
+            self.key
+        ));
+
+        let recv = eval.recv::<EtherWrapper<(), EtherError>>().await?;
+
+        self.unwrap(recv)
+    }
+
+    /*
+        Browser clock, unix time in milliseconds.
+    */
+    pub async fn date_now(&self) -> Result<u64, Error> {
+        let mut eval = document::eval(&format!("await dioxus.send({}.date_now())", self.key));
+
+        let recv = eval.recv::<EtherWrapper<u64, EtherError>>().await?;
+
+        self.unwrap(recv)
+    }
+
     fn unwrap<D: Default, E: EtherErrorPort>(
         &self,
         target: EtherWrapper<D, E>,
@@ -65,13 +192,19 @@ impl EtherAdapter {
         if !target.success {
             let error = match target.error {
                 Some(r) => EtherError {
+                    method: target.method,
+                    code: r.code(),
                     message: r.message(),
                     stack: r.stack(),
+                    data: r.data(),
+                },
+                _ => EtherError {
+                    method: target.method,
+                    ..EtherError::new_unknown()
                 },
-                _ => EtherError::new_unknown(),
             };
 
-            return Err(format!("[{}]: {}", target.method, error.message).into());
+            return Err(error.into());
         }
 
         Ok(target.data.unwrap_or_default())
@@ -79,10 +212,10 @@ impl EtherAdapter {
 }
 
 /*
-    Ethereum RPC method: eth_RequestAccounts -> Vec<String>;
+    Ethereum RPC method: eth_RequestAccounts -> Vec<Address>;
 */
 impl EtherRequestAccountsPort for EtherAdapter {
-    type Output = Vec<String>;
+    type Output = Vec<Address>;
     type Error = Error;
 
     async fn eth_request_accounts(&self) -> Result<Self::Output, Self::Error> {
@@ -91,33 +224,33 @@ impl EtherRequestAccountsPort for EtherAdapter {
             self.key
         ));
 
-        let recv = eval
-            .recv::<EtherWrapper<Self::Output, EtherError>>()
-            .await?;
+        let recv = eval.recv::<EtherWrapper<Vec<String>, EtherError>>().await?;
 
         let data = self.unwrap(recv)?;
 
-        Ok(data.iter().map(|i| i.to_lowercase().to_string()).collect())
+        data.iter()
+            .map(|i| self.ether_address.parse_address(i))
+            .collect()
     }
 }
 
 /*
-    Ethereum RPC method: eth_accounts -> Vec<String>;
+    Ethereum RPC method: eth_accounts -> Vec<Address>;
 */
 impl EtherAccountsPort for EtherAdapter {
-    type Output = Vec<String>;
+    type Output = Vec<Address>;
     type Error = Error;
 
     async fn eth_accounts(&self) -> Result<Self::Output, Self::Error> {
         let mut eval = document::eval(&format!("await dioxus.send({}.eth_accounts())", self.key));
 
-        let recv = eval
-            .recv::<EtherWrapper<Self::Output, EtherError>>()
-            .await?;
+        let recv = eval.recv::<EtherWrapper<Vec<String>, EtherError>>().await?;
 
         let data = self.unwrap(recv)?;
 
-        Ok(data.iter().map(|i| i.to_lowercase().to_string()).collect())
+        data.iter()
+            .map(|i| self.ether_address.parse_address(i))
+            .collect()
     }
 }
 
@@ -170,16 +303,26 @@ impl WalletSwitchEthereumChainPort for EtherAdapter {
 }
 
 /*
-    Ethereum RPC method: eth_blockNumber -> String;
+    Metamask wallet method: wallet_addEthereumChain -> ();
 */
-impl EtherBlockNumberPort for EtherAdapter {
-    type Output = String;
+impl WalletAddEthereumChainPort for EtherAdapter {
+    type Output = ();
 
     type Error = Error;
 
-    async fn eth_block_number(&self) -> Result<Self::Output, Self::Error> {
+    async fn wallet_add_ethereum_chain(
+        &self,
+        chain: &EtherChainParameters,
+    ) -> Result<Self::Output, Self::Error> {
+        let chain = EtherChainParameters {
+            chain_id: self.ether_hash.encode_to_number(&chain.chain_id)?,
+            ..chain.clone()
+        };
+
+        let payload = serde_json::to_string(&chain)?;
+
         let mut eval = document::eval(&format!(
-            "await dioxus.send({}.eth_blockNumber())",
+            "await dioxus.send({}.wallet_addEthereumChain({payload}))",
             self.key
         ));
 
@@ -187,49 +330,154 @@ impl EtherBlockNumberPort for EtherAdapter {
             .recv::<EtherWrapper<Self::Output, EtherError>>()
             .await?;
 
-        let data = self.unwrap(recv)?;
+        self.unwrap(recv)
+    }
+}
 
-        self.ether_hash.decode_to_number(&data)
+/*
+    Metamask wallet method: wallet_watchAsset -> EtherWatchAssetResult;
+*/
+impl WalletWatchAssetPort for EtherAdapter {
+    type Output = EtherWatchAssetResult;
+
+    type Error = Error;
+
+    async fn wallet_watch_asset(
+        &self,
+        asset: &EtherWatchAsset,
+    ) -> Result<Self::Output, Self::Error> {
+        let payload = match asset {
+            EtherWatchAsset::Erc20 {
+                address,
+                symbol,
+                decimals,
+                image,
+            } => {
+                let token = Erc20Adapter::with_provider(*address, self.clone());
+
+                // Only the missing fields are read, `name()` is optional in ERC-20;
+                let symbol = match symbol {
+                    Some(r) => r.clone(),
+                    None => token.symbol().await?,
+                };
+
+                let decimals = match decimals {
+                    Some(r) => *r,
+                    None => token.decimals().await?,
+                };
+
+                let mut options = serde_json::json!({
+                    "address": self.ether_address.checksum_address(address),
+                    "symbol": symbol,
+                    "decimals": decimals,
+                });
+
+                if let Some(image) = image {
+                    options["image"] = serde_json::Value::from(image.as_str());
+                }
+
+                serde_json::json!({ "type": "ERC20", "options": options })
+            }
+            EtherWatchAsset::Erc721 { address, token_id } => serde_json::json!({
+                "type": "ERC721",
+                "options": {
+                    "address": self.ether_address.checksum_address(address),
+                    "tokenId": token_id.to_string(),
+                },
+            }),
+            EtherWatchAsset::Erc1155 { address, token_id } => serde_json::json!({
+                "type": "ERC1155",
+                "options": {
+                    "address": self.ether_address.checksum_address(address),
+                    "tokenId": token_id.to_string(),
+                },
+            }),
+        };
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.wallet_watchAsset({payload}))",
+            self.key
+        ));
+
+        let recv = eval.recv::<EtherWrapper<bool, EtherError>>().await?;
+
+        match self.unwrap(recv) {
+            Ok(true) => Ok(EtherWatchAssetResult::Added),
+            Ok(false) => Ok(EtherWatchAssetResult::Rejected),
+            Err(e) => match EtherError::from_error(&e) {
+                Some(r) if r.is_user_rejected() => Ok(EtherWatchAssetResult::Rejected),
+                _ => Err(e),
+            },
+        }
     }
 }
 
 /*
-    Ethereum RPC method: eth_gasPrice -> String;
+    Metamask wallet method: wallet_requestPermissions -> Vec<EtherPermission>;
 */
-impl EtherGasPricePort for EtherAdapter {
-    type Output = String;
+impl WalletRequestPermissionsPort for EtherAdapter {
+    type Output = Vec<EtherPermission>;
 
     type Error = Error;
 
-    async fn eth_gas_price(&self) -> Result<Self::Output, Self::Error> {
-        let mut eval = document::eval(&format!("await dioxus.send({}.eth_gasPrice())", self.key));
+    async fn wallet_request_permissions(
+        &self,
+        capabilities: &[&str],
+    ) -> Result<Self::Output, Self::Error> {
+        let permissions = encode_permissions(capabilities);
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.wallet_requestPermissions({permissions}))",
+            self.key
+        ));
 
         let recv = eval
             .recv::<EtherWrapper<Self::Output, EtherError>>()
             .await?;
 
-        let data = self.unwrap(recv)?;
+        self.unwrap(recv)
+    }
+}
 
-        self.ether_hash.decode_to_number(&data)
+/*
+    Metamask wallet method: wallet_getPermissions -> Vec<EtherPermission>;
+*/
+impl WalletGetPermissionsPort for EtherAdapter {
+    type Output = Vec<EtherPermission>;
+
+    type Error = Error;
+
+    async fn wallet_get_permissions(&self) -> Result<Self::Output, Self::Error> {
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.wallet_getPermissions())",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
     }
 }
 
 /*
-    Ethereum RPC method: personal_sign -> String;
+    Metamask wallet method: wallet_revokePermissions -> ();
 */
-impl PersonalSignPort for EtherAdapter {
-    type Output = String;
+impl WalletRevokePermissionsPort for EtherAdapter {
+    type Output = ();
 
     type Error = Error;
 
-    async fn personal_sign(
+    async fn wallet_revoke_permissions(
         &self,
-        message: &str,
-        address: &str,
+        capabilities: &[&str],
     ) -> Result<Self::Output, Self::Error> {
+        let permissions = encode_permissions(capabilities);
+
         let mut eval = document::eval(&format!(
-            "await dioxus.send({}.personal_sign('{}', '{}'))",
-            self.key, message, address
+            "await dioxus.send({}.wallet_revokePermissions({permissions}))",
+            self.key
         ));
 
         let recv = eval
@@ -241,56 +489,89 @@ impl PersonalSignPort for EtherAdapter {
 }
 
 /*
-    Ethereum RPC method: eth_getBalance -> String;
+    Metamask wallet method: wallet_getCapabilities -> HashMap<String, EtherCapabilities>;
 */
-impl EtherGetBalancerPort for EtherAdapter {
-    type Output = String;
+impl WalletGetCapabilitiesPort for EtherAdapter {
+    type Output = HashMap<String, EtherCapabilities>;
 
     type Error = Error;
 
-    async fn eth_get_balance(
+    async fn wallet_get_capabilities(
         &self,
-        address: &str,
-        block: Option<String>,
+        address: Address,
+        chain_ids: &[&str],
     ) -> Result<Self::Output, Self::Error> {
-        let block = block.unwrap_or(String::from("latest"));
+        let address = self.ether_address.checksum_address(&address);
+        let chain_ids = chain_ids
+            .iter()
+            .map(|r| r.parse::<ChainId>().map(|r| r.hex()))
+            .collect::<Result<Vec<_>, _>>()?;
 
         let mut eval = document::eval(&format!(
-            "await dioxus.send({}.eth_getBalance('{}', '{}'))",
-            self.key, address, block
+            "await dioxus.send({}.wallet_getCapabilities('{address}', {}))",
+            self.key,
+            serde_json::to_string(&chain_ids)?
         ));
 
         let recv = eval
             .recv::<EtherWrapper<Self::Output, EtherError>>()
             .await?;
 
-        let data = self.unwrap(recv)?;
-
-        self.ether_hash.decode_to_number(&data)
+        self.unwrap(recv)
     }
 }
 
 /*
-    Ethereum RPC method: eth_call -> String;
+    Metamask wallet method: wallet_sendCalls -> String;
 */
-impl EtherCallPort for EtherAdapter {
+impl WalletSendCallsPort for EtherAdapter {
     type Output = String;
 
     type Error = Error;
 
-    async fn eth_call(
-        &self,
-        address: &str,
-        calldata: &str,
-        block: Option<String>,
-    ) -> Result<Self::Output, Self::Error> {
-        let block = block.unwrap_or(String::from("latest"));
+    async fn wallet_send_calls(&self, calls: &EtherSendCalls) -> Result<Self::Output, Self::Error> {
+        let mut payload = serde_json::json!({
+            "version": "2.0.0",
+            "from": self.ether_address.checksum_address(&calls.from),
+            "chainId": calls.chain_id.parse::<ChainId>()?.hex(),
+            "atomicRequired": calls.atomic_required,
+            "calls": calls.calls,
+        });
+
+        if let Some(url) = &calls.paymaster_url {
+            payload["capabilities"] = serde_json::json!({ "paymasterService": { "url": url } });
+        }
 
         let mut eval = document::eval(&format!(
-            "await dioxus.send({}.eth_call('{address}', '{calldata}', '{block}'))",
+            "await dioxus.send({}.wallet_sendCalls({payload}))",
             self.key
         ));
 
+        let recv = eval
+            .recv::<EtherWrapper<EtherSendCallsResult, EtherError>>()
+            .await?;
+
+        match self.unwrap(recv)? {
+            EtherSendCallsResult::Id(r) | EtherSendCallsResult::Object { id: r } => Ok(r),
+        }
+    }
+}
+
+/*
+    Metamask wallet method: wallet_getCallsStatus -> EtherCallsStatus;
+*/
+impl WalletGetCallsStatusPort for EtherAdapter {
+    type Output = EtherCallsStatus;
+
+    type Error = Error;
+
+    async fn wallet_get_calls_status(&self, id: &str) -> Result<Self::Output, Self::Error> {
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.wallet_getCallsStatus({}))",
+            self.key,
+            serde_json::to_string(id)?
+        ));
+
         let recv = eval
             .recv::<EtherWrapper<Self::Output, EtherError>>()
             .await?;
@@ -300,49 +581,72 @@ impl EtherCallPort for EtherAdapter {
 }
 
 /*
-    Ethereum RPC method: eth_getTransactionReceipt -> Struct;
+    Metamask wallet method: wallet_showCallsStatus -> ();
 */
-#[derive(Default, serde::Serialize, serde::Deserialize)]
-pub struct EtherTransactionReceipt {
-    #[serde(rename(serialize = "block_hash", deserialize = "blockHash"))]
-    pub block_hash: String,
-    #[serde(rename(serialize = "block_number", deserialize = "blockNumber"))]
-    pub block_number: String,
-    #[serde(rename(serialize = "contract_address", deserialize = "contractAddress"))]
-    pub contract_address: Option<String>,
-    #[serde(rename(serialize = "cumulative_gas_used", deserialize = "cumulativeGasUsed"))]
-    pub cumulative_gas_used: String,
-    pub from: String,
-    #[serde(rename(serialize = "gas_used", deserialize = "gasUsed"))]
-    pub gas_used: String,
-    #[serde(rename(serialize = "blob_gas_used", deserialize = "blobGasUsed"))]
-    pub blob_gas_used: Option<String>,
-    // pub logs: Vec<String>, // Coming soon;
-    #[serde(rename(serialize = "effective_gas_price", deserialize = "effectiveGasPrice"))]
-    pub effective_gas_price: String,
-    #[serde(rename(serialize = "blob_gas_price", deserialize = "blobGasPrice"))]
-    pub blob_gas_price: Option<String>,
-    #[serde(rename(serialize = "logs_bloom", deserialize = "logsBloom"))]
-    pub logs_bloom: String,
-    pub status: String,
-    pub to: String,
-    #[serde(rename(serialize = "transaction_hash", deserialize = "transactionHash"))]
-    pub transaction_hash: String,
-    #[serde(rename(serialize = "transaction_index", deserialize = "transactionIndex"))]
-    pub transaction_index: String,
+impl WalletShowCallsStatusPort for EtherAdapter {
+    type Output = ();
+
+    type Error = Error;
+
+    async fn wallet_show_calls_status(&self, id: &str) -> Result<Self::Output, Self::Error> {
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.wallet_showCallsStatus({}))",
+            self.key,
+            serde_json::to_string(id)?
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
 }
 
-impl EtherGetTransactionReceiptPort for EtherAdapter {
-    type Output = EtherTransactionReceipt;
+impl EtherAdapter {
+    /*
+        Switch chain, on a `4902` unknown chain error add `chain` (or the registry entry
+        of `chain_id`) and switch again.
+    */
+    pub async fn switch_chain(
+        &self,
+        chain_id: &str,
+        chain: Option<&EtherChainParameters>,
+    ) -> Result<(), Error> {
+        let error = match self.wallet_switch_ethereum_chain(chain_id).await {
+            Ok(r) => return Ok(r),
+            Err(e) => e,
+        };
+
+        if !EtherError::from_error(&error).is_some_and(|r| r.is_unrecognized_chain()) {
+            return Err(error);
+        }
+
+        let chain = match chain {
+            Some(r) => r.clone(),
+            None => match known_chain(chain_id.parse::<ChainId>()?) {
+                Some(r) => r.parameters(),
+                None => return Err(error),
+            },
+        };
+
+        self.wallet_add_ethereum_chain(&chain).await?;
+
+        self.wallet_switch_ethereum_chain(chain_id).await
+    }
+}
+
+/*
+    Ethereum RPC method: eth_blockNumber -> String;
+*/
+impl EtherBlockNumberPort for EtherAdapter {
+    type Output = String;
 
     type Error = Error;
 
-    async fn eth_get_transaction_receipt(
-        &self,
-        transaction_hash: &str,
-    ) -> Result<Self::Output, Self::Error> {
+    async fn eth_block_number(&self) -> Result<Self::Output, Self::Error> {
         let mut eval = document::eval(&format!(
-            "await dioxus.send({}.eth_getTransactionReceipt('{transaction_hash}'))",
+            "await dioxus.send({}.eth_blockNumber())",
             self.key
         ));
 
@@ -350,36 +654,44 @@ impl EtherGetTransactionReceiptPort for EtherAdapter {
             .recv::<EtherWrapper<Self::Output, EtherError>>()
             .await?;
 
-        let receipt = self.unwrap(recv)?;
+        let data = self.unwrap(recv)?;
 
-        Ok(receipt)
+        self.ether_hash.decode_to_number(&data)
     }
 }
 
 /*
-    Ethereum RPC method: eth_sendTransaction -> String;
+    Ethereum RPC method: eth_gasPrice -> String;
 */
-impl EtherSendTransactionPort for EtherAdapter {
+impl EtherGasPricePort for EtherAdapter {
     type Output = String;
 
     type Error = Error;
 
-    async fn eth_send_transaction(
-        &self,
-        from: &str,
-        to: &str,
-        value: Option<String>,
-        calldata: Option<String>,
-        gas: Option<String>,
-        gas_price: Option<String>,
-    ) -> Result<Self::Output, Self::Error> {
-        let value = value.unwrap_or(String::new());
-        let calldata = calldata.unwrap_or(String::new());
-        let gas = gas.unwrap_or(String::new());
-        let gas_price = gas_price.unwrap_or(String::new());
+    async fn eth_gas_price(&self) -> Result<Self::Output, Self::Error> {
+        let mut eval = document::eval(&format!("await dioxus.send({}.eth_gasPrice())", self.key));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        let data = self.unwrap(recv)?;
+
+        self.ether_hash.decode_to_number(&data)
+    }
+}
+
+/*
+    Ethereum RPC method: eth_maxPriorityFeePerGas -> String;
+*/
+impl EtherMaxPriorityFeePerGasPort for EtherAdapter {
+    type Output = String;
+
+    type Error = Error;
 
+    async fn eth_max_priority_fee_per_gas(&self) -> Result<Self::Output, Self::Error> {
         let mut eval = document::eval(&format!(
-            "await dioxus.send({}.eth_sendTransaction('{from}', '{to}', '{value}', '{calldata}', '{gas}', '{gas_price}'))",
+            "await dioxus.send({}.eth_maxPriorityFeePerGas())",
             self.key
         ));
 
@@ -387,8 +699,963 @@ impl EtherSendTransactionPort for EtherAdapter {
             .recv::<EtherWrapper<Self::Output, EtherError>>()
             .await?;
 
-        let r = self.unwrap(recv);
+        let data = self.unwrap(recv)?;
+
+        self.ether_hash.decode_to_number(&data)
+    }
+}
 
-        Ok(r?)
+impl From<&str> for EtherPersonalSignMessage {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+/*
+    Ethereum RPC method: personal_sign -> EtherPersonalSignature;
+*/
+impl PersonalSignPort for EtherAdapter {
+    type Output = EtherPersonalSignature;
+
+    type Error = Error;
+
+    async fn personal_sign(
+        &self,
+        message: &EtherPersonalSignMessage,
+        address: &Address,
+    ) -> Result<Self::Output, Self::Error> {
+        let address = self.ether_address.checksum_address(address);
+        let payload = hex::encode_prefixed(message.as_bytes());
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.personal_sign('{payload}', '{address}'))",
+            self.key
+        ));
+
+        let recv = eval.recv::<EtherWrapper<String, EtherError>>().await?;
+
+        Ok(EtherPersonalSignature {
+            signature: self.unwrap(recv)?,
+            digest: message.digest(),
+        })
+    }
+}
+
+/*
+    Ethereum RPC method: eth_signTypedData_v4 -> EtherTypedDataSignature;
+*/
+impl EtherSignTypedDataPort for EtherAdapter {
+    type Output = EtherTypedDataSignature;
+
+    type Error = Error;
+
+    async fn eth_sign_typed_data_v4(
+        &self,
+        address: Address,
+        typed_data: &TypedData,
+    ) -> Result<Self::Output, Self::Error> {
+        let checksum = self.ether_address.checksum_address(&address);
+        let signing_hash = typed_data.eip712_signing_hash()?;
+
+        let payload = serde_json::to_string(&serde_json::to_string(typed_data)?)?;
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_signTypedData_v4('{checksum}', {payload}))",
+            self.key
+        ));
+
+        let recv = eval.recv::<EtherWrapper<String, EtherError>>().await?;
+
+        let signature = self.unwrap(recv)?;
+
+        /*
+            The signing hash is only meaningful when the wallet signed the same payload:
+            an EOA must recover to `address`, contract accounts are checked with EIP-1271.
+        */
+        let signer = EtherSignatureAdapter::with_provider(self.clone());
+
+        if signer.recover_hash(&signing_hash, &signature).ok() != Some(address)
+            && !signer
+                .verify_hash(&address, &signing_hash, &signature)
+                .await?
+        {
+            return Err(format!(
+                "[eth_signTypedData_v4]: signature does not match {}",
+                checksum
+            )
+            .into());
+        }
+
+        Ok(EtherTypedDataSignature {
+            signature,
+            signing_hash,
+        })
+    }
+}
+
+/*
+    Ethereum RPC method: eth_getBalance -> String;
+*/
+impl EtherGetBalancerPort for EtherAdapter {
+    type Output = String;
+
+    type Error = Error;
+
+    async fn eth_get_balance(
+        &self,
+        address: Address,
+        block: Option<String>,
+    ) -> Result<Self::Output, Self::Error> {
+        let address = self.ether_address.checksum_address(&address);
+        let block = block.unwrap_or(String::from("latest"));
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_getBalance('{}', '{}'))",
+            self.key, address, block
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        let data = self.unwrap(recv)?;
+
+        self.ether_hash.decode_to_number(&data)
+    }
+}
+
+/*
+    Ethereum RPC method: eth_call -> String;
+*/
+impl EtherCallPort for EtherAdapter {
+    type Output = String;
+
+    type Error = Error;
+
+    async fn eth_call(
+        &self,
+        address: Address,
+        calldata: &str,
+        block: Option<String>,
+    ) -> Result<Self::Output, Self::Error> {
+        let address = self.ether_address.checksum_address(&address);
+        let block = block.unwrap_or(String::from("latest"));
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_call('{address}', '{calldata}', '{block}'))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
+}
+
+/*
+    Ethereum log object, shared by receipts and log queries;
+*/
+#[derive(Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EtherLog {
+    pub address: String,
+    pub topics: Vec<String>,
+    pub data: String,
+    #[serde(rename(serialize = "block_hash", deserialize = "blockHash"))]
+    pub block_hash: Option<String>,
+    #[serde(rename(serialize = "block_number", deserialize = "blockNumber"))]
+    pub block_number: Option<String>,
+    #[serde(rename(serialize = "transaction_hash", deserialize = "transactionHash"))]
+    pub transaction_hash: Option<String>,
+    #[serde(rename(serialize = "transaction_index", deserialize = "transactionIndex"))]
+    pub transaction_index: Option<String>,
+    #[serde(rename(serialize = "log_index", deserialize = "logIndex"))]
+    pub log_index: Option<String>,
+    #[serde(default)]
+    pub removed: bool,
+}
+
+impl EtherLog {
+    pub fn to_log(&self) -> Result<Log, Error> {
+        let address = Address::from_str(&self.address)?;
+        let data = Bytes::from_str(&self.data)?;
+
+        let mut topics = Vec::with_capacity(self.topics.len());
+
+        for topic in self.topics.iter() {
+            topics.push(B256::from_str(topic)?);
+        }
+
+        Log::new(address, topics, data).ok_or_else(|| "Log has more than 4 topics".into())
+    }
+}
+
+/*
+    Ethereum RPC method: eth_getTransactionReceipt -> Struct;
+*/
+#[derive(Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EtherTransactionReceipt {
+    #[serde(rename(serialize = "block_hash", deserialize = "blockHash"))]
+    pub block_hash: String,
+    #[serde(rename(serialize = "block_number", deserialize = "blockNumber"))]
+    pub block_number: String,
+    #[serde(rename(serialize = "contract_address", deserialize = "contractAddress"))]
+    pub contract_address: Option<String>,
+    #[serde(rename(serialize = "cumulative_gas_used", deserialize = "cumulativeGasUsed"))]
+    pub cumulative_gas_used: String,
+    pub from: String,
+    #[serde(rename(serialize = "gas_used", deserialize = "gasUsed"))]
+    pub gas_used: String,
+    #[serde(rename(serialize = "blob_gas_used", deserialize = "blobGasUsed"))]
+    pub blob_gas_used: Option<String>,
+    pub logs: Vec<EtherLog>,
+    #[serde(rename(serialize = "effective_gas_price", deserialize = "effectiveGasPrice"))]
+    pub effective_gas_price: String,
+    #[serde(rename(serialize = "blob_gas_price", deserialize = "blobGasPrice"))]
+    pub blob_gas_price: Option<String>,
+    #[serde(rename(serialize = "logs_bloom", deserialize = "logsBloom"))]
+    pub logs_bloom: String,
+    pub status: String,
+    pub to: String,
+    #[serde(rename(serialize = "transaction_hash", deserialize = "transactionHash"))]
+    pub transaction_hash: String,
+    #[serde(rename(serialize = "transaction_index", deserialize = "transactionIndex"))]
+    pub transaction_index: String,
+}
+
+impl EtherTransactionReceipt {
+    /*
+        Decode every log matching one of the interface events, e.g. `WETH_ABI::WETH_ABIEvents`.
+        Logs emitted by other contracts, unknown events and malformed logs are skipped.
+    */
+    pub fn decode_logs<E: SolEventInterface>(&self) -> Vec<Log<E>> {
+        self.logs
+            .iter()
+            .filter_map(|r| E::decode_log(&r.to_log().ok()?).ok())
+            .collect()
+    }
+
+    /*
+        Decode only the logs of a single event, matched by its signature hash.
+        Matching logs that fail to decode (e.g. a different indexing) are skipped.
+    */
+    pub fn filter_logs<E: SolEvent>(&self) -> Vec<Log<E>> {
+        if E::ANONYMOUS {
+            return Vec::new();
+        }
+
+        self.logs
+            .iter()
+            .filter_map(|r| r.to_log().ok())
+            .filter(|r| r.topics().first() == Some(&E::SIGNATURE_HASH))
+            .filter_map(|r| E::decode_log(&r).ok())
+            .collect()
+    }
+}
+
+impl EtherGetTransactionReceiptPort for EtherAdapter {
+    type Output = EtherTransactionReceipt;
+
+    type Error = Error;
+
+    async fn eth_get_transaction_receipt(
+        &self,
+        transaction_hash: &str,
+    ) -> Result<Self::Output, Self::Error> {
+        let transaction_hash = transaction_hash.parse::<B256>().map_err(|_| {
+            format!(
+                "[eth_getTransactionReceipt]: invalid transaction hash {}",
+                transaction_hash
+            )
+        })?;
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_getTransactionReceipt('{transaction_hash}'))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        let receipt = self.unwrap(recv)?;
+
+        Ok(receipt)
+    }
+}
+
+impl EtherGetLogsPort for EtherAdapter {
+    type Output = Vec<EtherLog>;
+
+    type Error = Error;
+
+    async fn eth_get_logs(&self, filter: &EtherLogFilter) -> Result<Self::Output, Self::Error> {
+        let filter = serde_json::to_string(filter)?;
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_getLogs({filter}))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
+}
+
+/*
+    Ethereum RPC method: eth_newFilter -> String;
+*/
+impl EtherNewFilterPort for EtherAdapter {
+    type Output = String;
+
+    type Error = Error;
+
+    async fn eth_new_filter(&self, filter: &EtherLogFilter) -> Result<Self::Output, Self::Error> {
+        let filter = serde_json::to_string(filter)?;
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_newFilter({filter}))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
+}
+
+/*
+    Ethereum RPC method: eth_getFilterChanges -> Vec<Struct>;
+*/
+impl EtherGetFilterChangesPort for EtherAdapter {
+    type Output = Vec<EtherLog>;
+
+    type Error = Error;
+
+    async fn eth_get_filter_changes(&self, filter_id: &str) -> Result<Self::Output, Self::Error> {
+        let filter_id = self.encode_hex("eth_getFilterChanges", filter_id)?;
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_getFilterChanges('{filter_id}'))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
+}
+
+/*
+    Ethereum RPC method: eth_uninstallFilter -> bool;
+*/
+impl EtherUninstallFilterPort for EtherAdapter {
+    type Output = bool;
+
+    type Error = Error;
+
+    async fn eth_uninstall_filter(&self, filter_id: &str) -> Result<Self::Output, Self::Error> {
+        let filter_id = self.encode_hex("eth_uninstallFilter", filter_id)?;
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_uninstallFilter('{filter_id}'))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
+}
+
+impl EtherAdapter {
+    /*
+        Query logs in chunks of `chunk_size` blocks, passing every chunk to `on_chunk` in block order.
+        A chunk rejected by the provider limits is split in half and retried.
+    */
+    pub async fn eth_get_logs_paginated<F: FnMut(Vec<EtherLog>)>(
+        &self,
+        filter: &EtherLogFilter,
+        chunk_size: u64,
+        mut on_chunk: F,
+    ) -> Result<(), Error> {
+        if filter.block_hash.is_some() {
+            on_chunk(self.eth_get_logs(filter).await?);
+
+            return Ok(());
+        }
+
+        let from = self
+            .resolve_block_number(filter.from_block.as_deref())
+            .await?;
+        let to = self
+            .resolve_block_number(filter.to_block.as_deref())
+            .await?;
+
+        let chunk_size = chunk_size.max(1);
+        let mut size = chunk_size;
+        let mut start = from;
+
+        while start <= to {
+            let end = to.min(start.saturating_add(size - 1));
+
+            let chunk = filter
+                .clone()
+                .from_block(&format!("0x{:x}", start))
+                .to_block(&format!("0x{:x}", end));
+
+            match self.eth_get_logs(&chunk).await {
+                Ok(r) => {
+                    on_chunk(r);
+
+                    start = end + 1;
+                    size = chunk_size.min(size.saturating_mul(2));
+                }
+                Err(e)
+                    if end > start
+                        && EtherError::from_error(&e)
+                            .is_some_and(|r| r.is_logs_limit_exceeded()) =>
+                {
+                    size = (end - start).div_ceil(2);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+
+    /*
+        Block tag or quantity to a block number, `safe` and `finalized` are read from
+        their block since they trail the head.
+    */
+    pub async fn resolve_block_number(&self, block: Option<&str>) -> Result<u64, Error> {
+        match block.unwrap_or("latest") {
+            "earliest" => Ok(0),
+            "latest" | "pending" => Ok(self.eth_block_number().await?.parse::<u64>()?),
+            r @ ("safe" | "finalized") => {
+                let block = self
+                    .eth_get_block_by_number(r, false)
+                    .await?
+                    .ok_or_else(|| format!("[eth_getBlockByNumber]: no {} block", r))?;
+
+                Ok(u64::from_str_radix(
+                    block.number.trim_start_matches("0x"),
+                    16,
+                )?)
+            }
+            r => match r.strip_prefix("0x") {
+                Some(hex) => Ok(u64::from_str_radix(hex, 16)?),
+                _ => Ok(r.parse::<u64>()?),
+            },
+        }
+    }
+}
+
+/*
+    Ethereum transaction object, returned by full blocks and transaction lookups;
+*/
+#[derive(Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EtherTransaction {
+    pub hash: String,
+    pub nonce: String,
+    #[serde(rename(serialize = "block_hash", deserialize = "blockHash"))]
+    pub block_hash: Option<String>,
+    #[serde(rename(serialize = "block_number", deserialize = "blockNumber"))]
+    pub block_number: Option<String>,
+    #[serde(rename(serialize = "transaction_index", deserialize = "transactionIndex"))]
+    pub transaction_index: Option<String>,
+    pub from: String,
+    pub to: Option<String>,
+    pub value: String,
+    pub gas: String,
+    #[serde(rename(serialize = "gas_price", deserialize = "gasPrice"))]
+    pub gas_price: Option<String>,
+    #[serde(rename(serialize = "max_fee_per_gas", deserialize = "maxFeePerGas"))]
+    pub max_fee_per_gas: Option<String>,
+    #[serde(rename(
+        serialize = "max_priority_fee_per_gas",
+        deserialize = "maxPriorityFeePerGas"
+    ))]
+    pub max_priority_fee_per_gas: Option<String>,
+    pub input: String,
+    #[serde(rename = "type")]
+    pub transaction_type: Option<String>,
+    #[serde(rename(serialize = "chain_id", deserialize = "chainId"))]
+    pub chain_id: Option<String>,
+    pub v: Option<String>,
+    pub r: Option<String>,
+    pub s: Option<String>,
+}
+
+/*
+    Blocks carry transaction hashes or full transaction objects, depending on the request flag;
+*/
+#[derive(Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum EtherBlockTransactions {
+    Hashes(Vec<String>),
+    Full(Vec<EtherTransaction>),
+}
+
+impl Default for EtherBlockTransactions {
+    fn default() -> Self {
+        Self::Hashes(Vec::new())
+    }
+}
+
+impl EtherBlockTransactions {
+    pub fn hashes(&self) -> Vec<String> {
+        match self {
+            Self::Hashes(r) => r.clone(),
+            Self::Full(r) => r.iter().map(|i| i.hash.to_string()).collect(),
+        }
+    }
+}
+
+/*
+    Ethereum RPC method: eth_getBlockByNumber, eth_getBlockByHash -> Struct, `null` for an unknown block;
+*/
+#[derive(Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EtherBlock {
+    pub hash: String,
+    #[serde(rename(serialize = "parent_hash", deserialize = "parentHash"))]
+    pub parent_hash: String,
+    pub number: String,
+    pub timestamp: String,
+    #[serde(rename(serialize = "base_fee_per_gas", deserialize = "baseFeePerGas"))]
+    pub base_fee_per_gas: Option<String>,
+    #[serde(rename(serialize = "gas_used", deserialize = "gasUsed"))]
+    pub gas_used: String,
+    #[serde(rename(serialize = "gas_limit", deserialize = "gasLimit"))]
+    pub gas_limit: String,
+    pub miner: String,
+    #[serde(rename(serialize = "logs_bloom", deserialize = "logsBloom"))]
+    pub logs_bloom: String,
+    #[serde(rename(serialize = "state_root", deserialize = "stateRoot"))]
+    pub state_root: String,
+    #[serde(rename(serialize = "receipts_root", deserialize = "receiptsRoot"))]
+    pub receipts_root: String,
+    #[serde(rename(serialize = "transactions_root", deserialize = "transactionsRoot"))]
+    pub transactions_root: String,
+    #[serde(rename(serialize = "extra_data", deserialize = "extraData"))]
+    pub extra_data: String,
+    pub size: Option<String>,
+    #[serde(rename(serialize = "blob_gas_used", deserialize = "blobGasUsed"))]
+    pub blob_gas_used: Option<String>,
+    #[serde(rename(serialize = "excess_blob_gas", deserialize = "excessBlobGas"))]
+    pub excess_blob_gas: Option<String>,
+    #[serde(default)]
+    pub transactions: EtherBlockTransactions,
+}
+
+impl EtherBlock {
+    pub fn block_number(&self) -> Result<u64, Error> {
+        Ok(u64::from_str_radix(
+            self.number.trim_start_matches("0x"),
+            16,
+        )?)
+    }
+
+    /*
+        Unix timestamp in seconds.
+    */
+    pub fn block_timestamp(&self) -> Result<u64, Error> {
+        Ok(u64::from_str_radix(
+            self.timestamp.trim_start_matches("0x"),
+            16,
+        )?)
+    }
+
+    /*
+        Number of blocks on top of this one including itself, 0 while it is ahead of `latest`.
+    */
+    pub fn confirmations(&self, latest: u64) -> Result<u64, Error> {
+        let number = self.block_number()?;
+
+        Ok(match latest >= number {
+            true => latest - number + 1,
+            false => 0,
+        })
+    }
+}
+
+/*
+    Ethereum RPC method: eth_getBlockByNumber -> Option<Struct>;
+*/
+impl EtherGetBlockByNumberPort for EtherAdapter {
+    type Output = Option<EtherBlock>;
+
+    type Error = Error;
+
+    async fn eth_get_block_by_number(
+        &self,
+        block: &str,
+        full_transactions: bool,
+    ) -> Result<Self::Output, Self::Error> {
+        let block = self.encode_quantity("eth_getBlockByNumber", block)?;
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_getBlockByNumber('{block}', {full_transactions}))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
+}
+
+/*
+    Ethereum RPC method: eth_getBlockByHash -> Option<Struct>;
+*/
+impl EtherGetBlockByHashPort for EtherAdapter {
+    type Output = Option<EtherBlock>;
+
+    type Error = Error;
+
+    async fn eth_get_block_by_hash(
+        &self,
+        block_hash: &str,
+        full_transactions: bool,
+    ) -> Result<Self::Output, Self::Error> {
+        let block_hash = block_hash
+            .parse::<B256>()
+            .map_err(|_| format!("[eth_getBlockByHash]: invalid block hash {}", block_hash))?;
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_getBlockByHash('{block_hash}', {full_transactions}))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
+}
+
+/*
+    Ethereum RPC method: eth_getBlockReceipts -> Vec<Struct>;
+*/
+impl EtherGetBlockReceiptsPort for EtherAdapter {
+    type Output = Vec<EtherTransactionReceipt>;
+
+    type Error = Error;
+
+    async fn eth_get_block_receipts(&self, block: &str) -> Result<Self::Output, Self::Error> {
+        let block = self.encode_quantity("eth_getBlockReceipts", block)?;
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_getBlockReceipts('{block}'))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
+}
+
+/*
+    Ethereum RPC method: eth_getTransactionCount -> String;
+*/
+impl EtherGetTransactionCountPort for EtherAdapter {
+    type Output = String;
+
+    type Error = Error;
+
+    async fn eth_get_transaction_count(
+        &self,
+        address: Address,
+        block: Option<String>,
+    ) -> Result<Self::Output, Self::Error> {
+        let address = self.ether_address.checksum_address(&address);
+        let block = block.unwrap_or(String::from("latest"));
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_getTransactionCount('{address}', '{block}'))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        let data = self.unwrap(recv)?;
+
+        self.ether_hash.decode_to_number(&data)
+    }
+}
+
+/*
+    Ethereum RPC method: eth_getCode -> String;
+*/
+impl EtherGetCodePort for EtherAdapter {
+    type Output = String;
+
+    type Error = Error;
+
+    async fn eth_get_code(
+        &self,
+        address: Address,
+        block: Option<String>,
+    ) -> Result<Self::Output, Self::Error> {
+        let address = self.ether_address.checksum_address(&address);
+        let block = block.unwrap_or(String::from("latest"));
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_getCode('{address}', '{block}'))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
+}
+
+/*
+    Externally owned accounts have no code, EIP-7702 delegated accounts (`0xef0100` designator)
+    are not treated as contracts.
+*/
+pub fn is_contract_code(code: &str) -> bool {
+    let code = code.trim_start_matches("0x");
+
+    !code.is_empty() && !code.starts_with("ef0100")
+}
+
+impl EtherAdapter {
+    pub async fn is_contract(&self, address: Address) -> Result<bool, Error> {
+        Ok(is_contract_code(&self.eth_get_code(address, None).await?))
+    }
+}
+
+/*
+    Ethereum RPC method: eth_getStorageAt -> String;
+*/
+impl EtherGetStorageAtPort for EtherAdapter {
+    type Output = String;
+
+    type Error = Error;
+
+    async fn eth_get_storage_at(
+        &self,
+        address: Address,
+        slot: &str,
+        block: Option<String>,
+    ) -> Result<Self::Output, Self::Error> {
+        let address = self.ether_address.checksum_address(&address);
+        let slot = self.encode_quantity("eth_getStorageAt", slot)?;
+        let block = block.unwrap_or(String::from("latest"));
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_getStorageAt('{address}', '{slot}', '{block}'))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
+}
+
+/*
+    Ethereum RPC method: eth_getTransactionByHash -> Option<Struct>, `null` for an unknown or dropped transaction;
+*/
+impl EtherGetTransactionByHashPort for EtherAdapter {
+    type Output = Option<EtherTransaction>;
+
+    type Error = Error;
+
+    async fn eth_get_transaction_by_hash(
+        &self,
+        transaction_hash: &str,
+    ) -> Result<Self::Output, Self::Error> {
+        let transaction_hash = transaction_hash.parse::<B256>().map_err(|_| {
+            format!(
+                "[eth_getTransactionByHash]: invalid transaction hash {}",
+                transaction_hash
+            )
+        })?;
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_getTransactionByHash('{transaction_hash}'))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
+}
+
+/*
+    Ethereum RPC method: eth_getTransactionByBlockNumberAndIndex -> Option<Struct>, `null` for an unknown or dropped transaction;
+*/
+impl EtherGetTransactionByBlockNumberAndIndexPort for EtherAdapter {
+    type Output = Option<EtherTransaction>;
+
+    type Error = Error;
+
+    async fn eth_get_transaction_by_block_number_and_index(
+        &self,
+        block: &str,
+        index: &str,
+    ) -> Result<Self::Output, Self::Error> {
+        let block = self.encode_quantity("eth_getTransactionByBlockNumberAndIndex", block)?;
+        let index = self.encode_quantity("eth_getTransactionByBlockNumberAndIndex", index)?;
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_getTransactionByBlockNumberAndIndex('{block}', '{index}'))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
+}
+
+/*
+    Ethereum RPC method: eth_estimateGas -> String;
+*/
+impl EtherEstimateGasPort for EtherAdapter {
+    type Output = String;
+
+    type Error = Error;
+
+    async fn eth_estimate_gas(
+        &self,
+        from: Option<Address>,
+        to: Address,
+        value: Option<String>,
+        calldata: Option<String>,
+    ) -> Result<Self::Output, Self::Error> {
+        let from = from
+            .map(|r| self.ether_address.checksum_address(&r))
+            .unwrap_or_default();
+        let to = self.ether_address.checksum_address(&to);
+        let value = value.unwrap_or_default();
+        let calldata = calldata.unwrap_or_default();
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_estimateGas('{from}', '{to}', '{value}', '{calldata}'))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        let data = self.unwrap(recv)?;
+
+        self.ether_hash.decode_to_number(&data)
+    }
+}
+
+/*
+    Ethereum RPC method: eth_sendTransaction -> String;
+*/
+impl EtherSendTransactionPort for EtherAdapter {
+    type Output = String;
+
+    type Error = Error;
+
+    async fn eth_send_transaction(
+        &self,
+        from: Address,
+        to: Address,
+        value: Option<String>,
+        calldata: Option<String>,
+        gas: Option<String>,
+        gas_price: Option<String>,
+    ) -> Result<Self::Output, Self::Error> {
+        let from = self.ether_address.checksum_address(&from);
+        let to = self.ether_address.checksum_address(&to);
+        let value = value.unwrap_or_default();
+        let calldata = calldata.unwrap_or_default();
+        let gas = gas.unwrap_or_default();
+        let gas_price = gas_price.unwrap_or_default();
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.eth_sendTransaction('{from}', '{to}', '{value}', '{calldata}', '{gas}', '{gas_price}'))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
+}
+
+/*
+    Non-ethereum method: http_get -> String;
+*/
+impl HttpGetPort for EtherAdapter {
+    type Output = String;
+
+    type Error = Error;
+
+    async fn http_get(&self, url: &str) -> Result<Self::Output, Self::Error> {
+        let url = serde_json::to_string(url)?;
+
+        let mut eval = document::eval(&format!("await dioxus.send({}.http_get({url}))", self.key));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
+    }
+}
+
+/*
+    Non-ethereum method: http_post -> String;
+*/
+impl HttpPostPort for EtherAdapter {
+    type Output = String;
+
+    type Error = Error;
+
+    async fn http_post(&self, url: &str, body: &str) -> Result<Self::Output, Self::Error> {
+        let url = serde_json::to_string(url)?;
+        let body = serde_json::to_string(body)?;
+
+        let mut eval = document::eval(&format!(
+            "await dioxus.send({}.http_post({url}, {body}))",
+            self.key
+        ));
+
+        let recv = eval
+            .recv::<EtherWrapper<Self::Output, EtherError>>()
+            .await?;
+
+        self.unwrap(recv)
     }
 }
@@ -0,0 +1,79 @@
+use alloy_primitives::{Address, hex};
+
+use crate::{
+    adapters::ether_chain_adapter::{ChainId, known_chain},
+    errors::{error::Error, ether_address_error::EtherAddressError},
+    ports::ether_address_port::EtherAddressPort,
+};
+
+/*
+    EIP-55 addresses, or EIP-1191 when a chain id is set (RSK style chain specific checksums).
+*/
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct EtherAddressAdapter {
+    chain_id: Option<u64>,
+}
+
+impl EtherAddressAdapter {
+    pub fn new() -> Self {
+        Self { chain_id: None }
+    }
+
+    pub fn with_chain_id(chain_id: u64) -> Self {
+        Self {
+            chain_id: Some(chain_id),
+        }
+    }
+
+    /*
+        EIP-1191 on chains registered with it (Rootstock), EIP-55 everywhere else,
+        e.g. `EtherAddressAdapter::for_chain(connected_chain_id)` for inputs and display.
+    */
+    pub fn for_chain(chain_id: ChainId) -> Self {
+        match known_chain(chain_id).is_some_and(|r| r.eip1191) {
+            true => Self::with_chain_id(chain_id.0),
+            false => Self::new(),
+        }
+    }
+}
+
+impl EtherAddressPort for EtherAddressAdapter {
+    type Error = Error;
+
+    /*
+        All lowercase or all uppercase addresses carry no checksum, mixed case ones must match it.
+    */
+    fn parse_address(&self, address: &str) -> Result<Address, Self::Error> {
+        let value = address.trim();
+        let digits = value.strip_prefix("0x").unwrap_or(value);
+
+        if digits.len() != 40 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(EtherAddressError::InvalidAddress(address.to_string()).into());
+        }
+
+        let parsed = Address::from_slice(&hex::decode(digits)?);
+
+        let lowercase = digits.bytes().all(|b| !b.is_ascii_uppercase());
+        let uppercase = digits.bytes().all(|b| !b.is_ascii_lowercase());
+
+        if lowercase || uppercase {
+            return Ok(parsed);
+        }
+
+        match parsed.to_checksum(self.chain_id)[2..] == *digits {
+            true => Ok(parsed),
+            false => Err(EtherAddressError::InvalidChecksum(address.to_string()).into()),
+        }
+    }
+
+    fn checksum_address(&self, address: &Address) -> String {
+        address.to_checksum(self.chain_id)
+    }
+}
+
+/*
+    Shortcut for `EtherAddressAdapter::new().parse_address`, EIP-55 only.
+*/
+pub fn parse_address(address: &str) -> Result<Address, Error> {
+    EtherAddressAdapter::new().parse_address(address)
+}
@@ -0,0 +1,409 @@
+use gloo_timers::future::sleep;
+use std::{collections::HashMap, time::Duration};
+
+use crate::{
+    adapters::{
+        ether_adapter::{EtherAdapter, EtherTransactionReceipt},
+        ether_chain_adapter::ChainId,
+    },
+    errors::{error::Error, ether_calls_error::EtherCallsError, ether_error::EtherError},
+    ports::ether::{
+        ether_get_transaction_receipt_port::EtherGetTransactionReceiptPort,
+        ether_send_transaction_port::EtherSendTransactionPort,
+        wallet_get_capabilities_port::WalletGetCapabilitiesPort,
+        wallet_send_calls_port::WalletSendCallsPort,
+    },
+    types::ether_calls_types::{EtherCapabilities, EtherSendCalls, EtherSentCalls},
+};
+
+// Receipt polls of the sequential fallback before giving up;
+const MAX_RECEIPT_POLLS: usize = 300;
+
+/*
+    `wallet_getCapabilities` result keyed by `0x` chain id, merged with the `0x0` entry that
+    applies to every chain.
+*/
+pub fn chain_capabilities(
+    capabilities: &HashMap<String, EtherCapabilities>,
+    chain_id: ChainId,
+) -> Option<EtherCapabilities> {
+    let all = capabilities.get("0x0");
+
+    match capabilities.get(&chain_id.hex()) {
+        Some(r) => Some(EtherCapabilities {
+            atomic: r.atomic.clone().or(all.and_then(|i| i.atomic.clone())),
+            atomic_batch: r
+                .atomic_batch
+                .clone()
+                .or(all.and_then(|i| i.atomic_batch.clone())),
+            paymaster_service: r
+                .paymaster_service
+                .clone()
+                .or(all.and_then(|i| i.paymaster_service.clone())),
+        }),
+        None => all.cloned(),
+    }
+}
+
+pub struct EtherCallsAdapter<P = EtherAdapter> {
+    provider: P,
+    poll_interval: Duration,
+}
+
+impl Default for EtherCallsAdapter<EtherAdapter> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EtherCallsAdapter<EtherAdapter> {
+    pub fn new() -> Self {
+        Self::with_provider(EtherAdapter::new())
+    }
+}
+
+impl<P> EtherCallsAdapter<P> {
+    pub fn with_provider(provider: P) -> Self {
+        Self {
+            provider,
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+
+    /*
+        Receipt polling interval of the sequential fallback.
+    */
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+}
+
+impl<P> EtherCallsAdapter<P>
+where
+    P: WalletGetCapabilitiesPort<Output = HashMap<String, EtherCapabilities>, Error = Error>
+        + WalletSendCallsPort<Output = String, Error = Error>
+        + EtherSendTransactionPort<Output = String, Error = Error>
+        + EtherGetTransactionReceiptPort<Output = EtherTransactionReceipt, Error = Error>,
+{
+    /*
+        `wallet_sendCalls` when the wallet reports the needed capabilities. Without them a
+        batch that requires atomic execution or a paymaster fails, any other batch falls back
+        to one `eth_sendTransaction` per call, each mined before the next one is sent.
+    */
+    pub async fn send_calls(&self, calls: &EtherSendCalls) -> Result<EtherSentCalls, Error> {
+        let chain_id = calls.chain_id.parse::<ChainId>()?;
+
+        let capabilities = match self
+            .provider
+            .wallet_get_capabilities(calls.from, &[&chain_id.hex()])
+            .await
+        {
+            Ok(r) => chain_capabilities(&r, chain_id),
+            Err(e) if EtherError::from_error(&e).is_some_and(|r| r.is_user_rejected()) => {
+                return Err(e);
+            }
+            Err(_) => None,
+        };
+
+        let required = calls.atomic_required || calls.paymaster_url.is_some();
+
+        if let Some(capabilities) = &capabilities {
+            if calls.atomic_required && !capabilities.supports_atomic() {
+                return Err(EtherCallsError::AtomicUnsupported.into());
+            }
+
+            if calls.paymaster_url.is_some() && !capabilities.supports_paymaster() {
+                return Err(EtherCallsError::PaymasterUnsupported.into());
+            }
+
+            match self.provider.wallet_send_calls(calls).await {
+                Ok(r) => return Ok(EtherSentCalls::Batch(r)),
+                Err(e)
+                    if !required
+                        && EtherError::from_error(&e)
+                            .is_some_and(|r| r.is_unsupported_method()) => {}
+                Err(e) => return Err(e),
+            }
+        } else if calls.atomic_required {
+            return Err(EtherCallsError::AtomicUnsupported.into());
+        } else if calls.paymaster_url.is_some() {
+            return Err(EtherCallsError::PaymasterUnsupported.into());
+        }
+
+        self.send_sequential(calls).await
+    }
+
+    /*
+        A failure after the first transaction keeps the hashes that were already sent.
+    */
+    async fn send_sequential(&self, calls: &EtherSendCalls) -> Result<EtherSentCalls, Error> {
+        let mut hashes = Vec::with_capacity(calls.calls.len());
+
+        for (i, call) in calls.calls.iter().enumerate() {
+            let interrupted = |sent: &[String], reason: String| EtherCallsError::Interrupted {
+                sent: sent.to_vec(),
+                reason: format!("call {} failed: {}", i, reason),
+            };
+
+            let hash = match self
+                .provider
+                .eth_send_transaction(
+                    calls.from,
+                    call.to,
+                    call.value.clone(),
+                    call.data.clone(),
+                    None,
+                    None,
+                )
+                .await
+            {
+                Ok(r) => r,
+                Err(e) if hashes.is_empty() => return Err(e),
+                Err(e) => return Err(interrupted(&hashes, e.to_string()).into()),
+            };
+
+            hashes.push(hash.clone());
+
+            if i + 1 < calls.calls.len() {
+                let receipt = match self.wait_receipt(&hash).await {
+                    Ok(r) => r,
+                    Err(e) => return Err(interrupted(&hashes, e.to_string()).into()),
+                };
+
+                if receipt.status != "0x1" {
+                    let reason = format!("reverted in {}", hash);
+
+                    return Err(interrupted(&hashes, reason).into());
+                }
+            }
+        }
+
+        Ok(EtherSentCalls::Sequential(hashes))
+    }
+
+    async fn wait_receipt(&self, hash: &str) -> Result<EtherTransactionReceipt, Error> {
+        for _ in 0..MAX_RECEIPT_POLLS {
+            let receipt = self.provider.eth_get_transaction_receipt(hash).await?;
+
+            if !receipt.transaction_hash.is_empty() {
+                return Ok(receipt);
+            }
+
+            sleep(self.poll_interval).await;
+        }
+
+        Err(format!("[calls]: no receipt for {}", hash).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::block_on, types::ether_calls_types::EtherCall};
+    use alloy_primitives::{Address, address};
+    use std::sync::Mutex;
+
+    const FROM: Address = address!("0x2c7536E3605D9C16a7a3D7b1898e529396a65c23");
+    const TOKEN: Address = address!("0x7b79995e5f793a07bc00c21412e50ecae098e7f9");
+
+    /*
+        Wallet with the given `0xaa36a7` capabilities, `None` when `wallet_getCapabilities`
+        is not implemented. Transactions from `revert_from` on are mined as reverted.
+    */
+    #[derive(Default)]
+    struct StubWallet {
+        capabilities: Option<serde_json::Value>,
+        revert_from: Option<usize>,
+        batches: Mutex<usize>,
+        transactions: Mutex<Vec<String>>,
+    }
+
+    fn unsupported(method: &str) -> Error {
+        EtherError {
+            method: method.to_string(),
+            code: Some(-32601),
+            message: String::from("method not found"),
+            ..Default::default()
+        }
+        .into()
+    }
+
+    impl WalletGetCapabilitiesPort for StubWallet {
+        type Output = HashMap<String, EtherCapabilities>;
+        type Error = Error;
+
+        async fn wallet_get_capabilities(
+            &self,
+            _address: Address,
+            _chain_ids: &[&str],
+        ) -> Result<Self::Output, Self::Error> {
+            match &self.capabilities {
+                Some(r) => Ok(serde_json::from_value(
+                    serde_json::json!({ "0xaa36a7": r }),
+                )?),
+                None => Err(unsupported("wallet_getCapabilities")),
+            }
+        }
+    }
+
+    impl WalletSendCallsPort for StubWallet {
+        type Output = String;
+        type Error = Error;
+
+        async fn wallet_send_calls(
+            &self,
+            _calls: &EtherSendCalls,
+        ) -> Result<Self::Output, Self::Error> {
+            *self.batches.lock().unwrap() += 1;
+
+            Ok(String::from("0xbatch"))
+        }
+    }
+
+    impl EtherSendTransactionPort for StubWallet {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_send_transaction(
+            &self,
+            _from: Address,
+            _to: Address,
+            _value: Option<String>,
+            _calldata: Option<String>,
+            _gas: Option<String>,
+            _gas_price: Option<String>,
+        ) -> Result<Self::Output, Self::Error> {
+            let mut transactions = self.transactions.lock().unwrap();
+            let hash = format!("0x{:02x}", transactions.len());
+
+            transactions.push(hash.clone());
+
+            Ok(hash)
+        }
+    }
+
+    impl EtherGetTransactionReceiptPort for StubWallet {
+        type Output = EtherTransactionReceipt;
+        type Error = Error;
+
+        async fn eth_get_transaction_receipt(
+            &self,
+            transaction_hash: &str,
+        ) -> Result<Self::Output, Self::Error> {
+            let index = usize::from_str_radix(&transaction_hash[2..], 16)?;
+            let reverted = self.revert_from.is_some_and(|r| index >= r);
+
+            Ok(EtherTransactionReceipt {
+                transaction_hash: transaction_hash.to_string(),
+                status: String::from(if reverted { "0x0" } else { "0x1" }),
+                ..Default::default()
+            })
+        }
+    }
+
+    fn calls() -> EtherSendCalls {
+        EtherSendCalls::new(FROM, "11155111")
+            .call(EtherCall::new(TOKEN).data("0x01"))
+            .call(EtherCall::new(TOKEN).data("0x02"))
+            .call(EtherCall::new(TOKEN).data("0x03"))
+    }
+
+    fn send(
+        wallet: StubWallet,
+        calls: &EtherSendCalls,
+    ) -> (Result<EtherSentCalls, Error>, StubWallet) {
+        let adapter = EtherCallsAdapter::with_provider(wallet);
+        let sent = block_on(adapter.send_calls(calls));
+
+        (sent, adapter.provider)
+    }
+
+    fn calls_error(result: Result<EtherSentCalls, Error>) -> EtherCallsError {
+        result
+            .unwrap_err()
+            .downcast_ref::<EtherCallsError>()
+            .cloned()
+            .expect("calls error")
+    }
+
+    #[test]
+    fn batches_with_capabilities() {
+        let wallet = StubWallet {
+            capabilities: Some(serde_json::json!({ "atomic": { "status": "supported" } })),
+            ..Default::default()
+        };
+
+        let (sent, wallet) = send(wallet, &calls().atomic_required(true));
+
+        assert_eq!(
+            sent.unwrap(),
+            EtherSentCalls::Batch(String::from("0xbatch"))
+        );
+        assert!(wallet.transactions.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn falls_back_when_not_required() {
+        let (sent, _) = send(StubWallet::default(), &calls());
+
+        assert_eq!(
+            sent.unwrap(),
+            EtherSentCalls::Sequential(vec![
+                String::from("0x00"),
+                String::from("0x01"),
+                String::from("0x02")
+            ])
+        );
+    }
+
+    #[test]
+    fn fails_when_atomic_is_required() {
+        let (sent, wallet) = send(StubWallet::default(), &calls().atomic_required(true));
+
+        assert_eq!(calls_error(sent), EtherCallsError::AtomicUnsupported);
+        assert!(wallet.transactions.lock().unwrap().is_empty());
+
+        let wallet = StubWallet {
+            capabilities: Some(serde_json::json!({ "atomic": { "status": "unsupported" } })),
+            ..Default::default()
+        };
+
+        let (sent, wallet) = send(wallet, &calls().atomic_required(true));
+
+        assert_eq!(calls_error(sent), EtherCallsError::AtomicUnsupported);
+        assert_eq!(*wallet.batches.lock().unwrap(), 0);
+    }
+
+    #[test]
+    fn fails_when_paymaster_is_unsupported() {
+        let wallet = StubWallet {
+            capabilities: Some(serde_json::json!({ "atomic": { "status": "supported" } })),
+            ..Default::default()
+        };
+
+        let (sent, wallet) = send(wallet, &calls().paymaster_url("https://paymaster.test"));
+
+        assert_eq!(calls_error(sent), EtherCallsError::PaymasterUnsupported);
+        assert_eq!(*wallet.batches.lock().unwrap(), 0);
+        assert!(wallet.transactions.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn keeps_sent_hashes_on_revert() {
+        let wallet = StubWallet {
+            revert_from: Some(1),
+            ..Default::default()
+        };
+
+        let (sent, _) = send(wallet, &calls());
+
+        match calls_error(sent) {
+            EtherCallsError::Interrupted { sent, reason } => {
+                assert_eq!(sent, vec![String::from("0x00"), String::from("0x01")]);
+                assert_eq!(reason, "call 1 failed: reverted in 0x01");
+            }
+            r => panic!("unexpected {:?}", r),
+        }
+    }
+}
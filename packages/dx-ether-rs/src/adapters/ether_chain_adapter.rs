@@ -0,0 +1,315 @@
+use alloy_primitives::{Address, U256};
+use std::{
+    collections::HashMap,
+    fmt,
+    str::FromStr,
+    sync::{LazyLock, Mutex},
+};
+
+use crate::{
+    adapters::ether_units_adapter::EtherUnitsAdapter,
+    constants::{
+        chain_constants::{CHAINS, EIP1191_CHAIN_IDS},
+        ens_constants::ENS_REGISTRY_ADDRESSES,
+        multicall3_constants::{MULTICALL3_ADDRESS, MULTICALL3_CHAIN_ADDRESSES},
+    },
+    errors::error::Error,
+    ports::ether_units_port::EtherUnitsPort,
+    types::ether_chain_types::{EtherChainParameters, EtherNativeCurrency},
+    types::ether_units_types::EtherUnitsFormat,
+};
+
+/*
+    EIP-155 chain id, parsed from decimal or `0x` hex and displayed as decimal.
+*/
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct ChainId(pub u64);
+
+impl ChainId {
+    pub const MAINNET: Self = Self(1);
+    pub const OPTIMISM: Self = Self(10);
+    pub const BSC: Self = Self(56);
+    pub const GNOSIS: Self = Self(100);
+    pub const POLYGON: Self = Self(137);
+    pub const ZKSYNC_SEPOLIA: Self = Self(300);
+    pub const ZKSYNC: Self = Self(324);
+    pub const BASE: Self = Self(8453);
+    pub const HOLESKY: Self = Self(17000);
+    pub const LOCALHOST: Self = Self(31337);
+    pub const ARBITRUM: Self = Self(42161);
+    pub const AVALANCHE: Self = Self(43114);
+    pub const LINEA: Self = Self(59144);
+    pub const POLYGON_AMOY: Self = Self(80002);
+    pub const BASE_SEPOLIA: Self = Self(84532);
+    pub const ARBITRUM_SEPOLIA: Self = Self(421614);
+    pub const SCROLL: Self = Self(534352);
+    pub const SEPOLIA: Self = Self(11155111);
+    pub const OPTIMISM_SEPOLIA: Self = Self(11155420);
+
+    pub fn hex(&self) -> String {
+        format!("0x{:x}", self.0)
+    }
+}
+
+impl FromStr for ChainId {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+
+        let parsed = match value.strip_prefix("0x") {
+            Some(r) => u64::from_str_radix(r, 16),
+            None => value.parse::<u64>(),
+        };
+
+        parsed
+            .map(Self)
+            .map_err(|_| format!("[chain]: invalid chain id `{}`", value).into())
+    }
+}
+
+impl fmt::Display for ChainId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u64> for ChainId {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ChainId> for u64 {
+    fn from(value: ChainId) -> Self {
+        value.0
+    }
+}
+
+/*
+    Chain metadata, `multicall3` falls back to the deterministic deployment when unset.
+*/
+#[derive(Default, Clone, PartialEq, Debug)]
+pub struct EtherChain {
+    pub chain_id: ChainId,
+    pub name: String,
+    pub native_currency: EtherNativeCurrency,
+    pub rpc_urls: Vec<String>,
+    // Base URL without trailing slash, e.g. `https://etherscan.io`;
+    pub explorer_url: Option<String>,
+    pub icon_urls: Vec<String>,
+    pub testnet: bool,
+    // WETH-like token of the native currency;
+    pub wrapped_native: Option<String>,
+    pub multicall3: Option<String>,
+    pub ens_registry: Option<String>,
+    // Addresses are checksummed with the chain id, EIP-1191;
+    pub eip1191: bool,
+}
+
+impl EtherChain {
+    pub fn new(chain_id: ChainId, name: &str) -> Self {
+        Self {
+            chain_id,
+            name: name.to_string(),
+            native_currency: EtherNativeCurrency {
+                name: String::from("Ether"),
+                symbol: String::from("ETH"),
+                decimals: 18,
+            },
+            ..Default::default()
+        }
+    }
+
+    pub fn native_currency(mut self, name: &str, symbol: &str, decimals: u8) -> Self {
+        self.native_currency = EtherNativeCurrency {
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            decimals,
+        };
+        self
+    }
+
+    pub fn rpc_url(mut self, url: &str) -> Self {
+        self.rpc_urls.push(url.to_string());
+        self
+    }
+
+    pub fn explorer_url(mut self, url: &str) -> Self {
+        self.explorer_url = Some(url.trim_end_matches('/').to_string());
+        self
+    }
+
+    pub fn icon_url(mut self, url: &str) -> Self {
+        self.icon_urls.push(url.to_string());
+        self
+    }
+
+    pub fn testnet(mut self, testnet: bool) -> Self {
+        self.testnet = testnet;
+        self
+    }
+
+    pub fn wrapped_native(mut self, address: &str) -> Self {
+        self.wrapped_native = Some(address.to_string());
+        self
+    }
+
+    pub fn multicall3(mut self, address: &str) -> Self {
+        self.multicall3 = Some(address.to_string());
+        self
+    }
+
+    pub fn ens_registry(mut self, address: &str) -> Self {
+        self.ens_registry = Some(address.to_string());
+        self
+    }
+
+    pub fn eip1191(mut self, eip1191: bool) -> Self {
+        self.eip1191 = eip1191;
+        self
+    }
+
+    pub fn multicall3_address(&self) -> String {
+        self.multicall3
+            .clone()
+            .unwrap_or(String::from(MULTICALL3_ADDRESS))
+    }
+
+    /*
+        EIP-3085 parameters for `wallet_addEthereumChain`.
+    */
+    pub fn parameters(&self) -> EtherChainParameters {
+        EtherChainParameters {
+            chain_id: self.chain_id.hex(),
+            chain_name: self.name.clone(),
+            native_currency: self.native_currency.clone(),
+            rpc_urls: self.rpc_urls.clone(),
+            block_explorer_urls: self.explorer_url.iter().cloned().collect(),
+            icon_urls: self.icon_urls.clone(),
+        }
+    }
+
+    pub fn explorer_tx_url(&self, hash: &str) -> Option<String> {
+        self.explorer_url
+            .as_ref()
+            .map(|r| format!("{}/tx/{}", r, hash))
+    }
+
+    pub fn explorer_address_url(&self, address: &Address) -> Option<String> {
+        self.explorer_url
+            .as_ref()
+            .map(|r| format!("{}/address/{}", r, address))
+    }
+
+    pub fn explorer_block_url(&self, block: u64) -> Option<String> {
+        self.explorer_url
+            .as_ref()
+            .map(|r| format!("{}/block/{}", r, block))
+    }
+
+    /*
+        Native amount with the currency symbol, e.g. `0.5 ETH`.
+    */
+    pub fn format_native(&self, value: U256, options: &EtherUnitsFormat) -> Result<String, Error> {
+        let value =
+            EtherUnitsAdapter::new().format_with(value, self.native_currency.decimals, options)?;
+
+        Ok(format!("{} {}", value, self.native_currency.symbol))
+    }
+}
+
+static CHAINS_REGISTRY: LazyLock<Mutex<HashMap<ChainId, EtherChain>>> = LazyLock::new(|| {
+    let mut chains = CHAINS
+        .iter()
+        .map(|r| {
+            let (name, symbol, decimals) = r.currency;
+
+            let mut chain = EtherChain::new(ChainId(r.chain_id), r.name)
+                .native_currency(name, symbol, decimals)
+                .testnet(r.testnet);
+
+            chain.rpc_urls = r.rpc_urls.iter().map(|i| i.to_string()).collect();
+            chain.explorer_url = r.explorer_url.map(str::to_string);
+            chain.wrapped_native = r.wrapped_native.map(str::to_string);
+            chain.eip1191 = EIP1191_CHAIN_IDS.contains(&r.chain_id);
+
+            (chain.chain_id, chain)
+        })
+        .collect::<HashMap<_, _>>();
+
+    // Addresses are only attached to the chains listed above;
+    for (chain_id, address) in MULTICALL3_CHAIN_ADDRESSES {
+        if let Some(chain) = chains.get_mut(&ChainId(*chain_id)) {
+            chain.multicall3 = Some(address.to_string());
+        }
+    }
+
+    for (chain_id, address) in ENS_REGISTRY_ADDRESSES {
+        if let Some(chain) = chains.get_mut(&ChainId(*chain_id)) {
+            chain.ens_registry = Some(address.to_string());
+        }
+    }
+
+    Mutex::new(chains)
+});
+
+/*
+    Add or replace a chain, e.g. a devnet or an app specific L2. The name is required,
+    it ends up in `wallet_addEthereumChain`.
+*/
+pub fn register_chain(chain: EtherChain) -> Result<(), Error> {
+    if chain.name.trim().is_empty() {
+        return Err(format!("[chain]: chain {} needs a name", chain.chain_id).into());
+    }
+
+    let mut chains = CHAINS_REGISTRY
+        .lock()
+        .map_err(|_| "[chain]: registry unavailable")?;
+
+    chains.insert(chain.chain_id, chain);
+
+    Ok(())
+}
+
+/*
+    Edit a registered chain, unknown chains have to be added with `register_chain` first.
+*/
+pub fn update_chain(chain_id: ChainId, update: impl FnOnce(&mut EtherChain)) -> Result<(), Error> {
+    let mut chains = CHAINS_REGISTRY
+        .lock()
+        .map_err(|_| "[chain]: registry unavailable")?;
+
+    match chains.get_mut(&chain_id) {
+        Some(r) => {
+            update(r);
+            Ok(())
+        }
+        None => Err(format!("[chain]: chain {} is not registered", chain_id).into()),
+    }
+}
+
+pub fn known_chain(chain_id: ChainId) -> Option<EtherChain> {
+    CHAINS_REGISTRY
+        .lock()
+        .ok()
+        .and_then(|r| r.get(&chain_id).cloned())
+}
+
+pub fn known_chains() -> Vec<EtherChain> {
+    let mut chains = CHAINS_REGISTRY
+        .lock()
+        .map(|r| r.values().cloned().collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    chains.sort_by_key(|r| r.chain_id);
+    chains
+}
+
+pub fn explorer_tx_url(chain_id: ChainId, hash: &str) -> Option<String> {
+    known_chain(chain_id)?.explorer_tx_url(hash)
+}
+
+pub fn explorer_address_url(chain_id: ChainId, address: &Address) -> Option<String> {
+    known_chain(chain_id)?.explorer_address_url(address)
+}
@@ -2,8 +2,15 @@ use alloy_primitives::{U256, hex};
 
 use crate::{errors::error::Error, ports::ether_hash_port::EtherHashPort};
 
+#[derive(Clone)]
 pub struct EtherHashAdapter;
 
+impl Default for EtherHashAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl EtherHashAdapter {
     pub fn new() -> Self {
         Self
@@ -13,12 +20,18 @@ impl EtherHashAdapter {
 impl EtherHashPort for EtherHashAdapter {
     type Error = Error;
 
-    fn encode_to_string(&self, bytes: &Vec<u8>) -> Result<String, Self::Error> {
+    fn encode_to_string(&self, bytes: &[u8]) -> Result<String, Self::Error> {
         Ok(format!("0x{}", hex::encode(bytes)))
     }
 
+    /*
+        Decimal or `0x` hex number to a `0x` hex quantity.
+    */
     fn encode_to_number(&self, hash: &str) -> Result<String, Self::Error> {
-        let number = hash.parse::<u64>()?;
+        let number = match hash.strip_prefix("0x") {
+            Some(r) => u64::from_str_radix(r, 16)?,
+            None => hash.parse::<u64>()?,
+        };
 
         Ok(format!("0x{:x}", number))
     }
@@ -38,6 +51,6 @@ impl EtherHashPort for EtherHashAdapter {
     }
 
     fn decode_to_number(&self, hash: &str) -> Result<String, Self::Error> {
-        Ok(U256::from_str_radix(&hash.trim_start_matches("0x"), 16)?.to_string())
+        Ok(U256::from_str_radix(hash.trim_start_matches("0x"), 16)?.to_string())
     }
 }
@@ -0,0 +1,419 @@
+use alloy_primitives::{Address, B256, Bytes, Signature, eip191_hash_message, normalize_v};
+use alloy_sol_types::{SolCall, SolValue};
+
+use crate::{
+    abi::{erc1271_abi::ERC1271, multicall3_abi::Multicall3},
+    adapters::{
+        ether_adapter::{EtherAdapter, is_contract_code},
+        ether_hash_adapter::EtherHashAdapter,
+        multicall_adapter::multicall3_address,
+    },
+    constants::signature_constants::{ERC1271_MAGIC_VALUE, ERC6492_MAGIC_SUFFIX},
+    errors::error::Error,
+    ports::{
+        ether::{
+            ether_call_port::EtherCallPort, ether_chain_id_port::EtherChainIdPort,
+            ether_get_code_port::EtherGetCodePort,
+        },
+        ether_hash_port::EtherHashPort,
+        ether_signature_port::EtherSignaturePort,
+    },
+    types::ether_signature_types::EtherSignatureParts,
+};
+
+pub struct EtherSignatureAdapter<P = EtherAdapter> {
+    provider: P,
+    ether_hash: EtherHashAdapter,
+}
+
+impl Default for EtherSignatureAdapter<EtherAdapter> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EtherSignatureAdapter<EtherAdapter> {
+    pub fn new() -> Self {
+        Self::with_provider(EtherAdapter::new())
+    }
+}
+
+impl<P> EtherSignatureAdapter<P> {
+    pub fn with_provider(provider: P) -> Self {
+        Self {
+            provider,
+            ether_hash: EtherHashAdapter::new(),
+        }
+    }
+
+    /*
+        65 bytes with `v` as 0/1, 27/28 or EIP-155, or 64 bytes EIP-2098 compact.
+    */
+    pub fn parse_signature(&self, signature: &str) -> Result<Signature, Error> {
+        let bytes = self.ether_hash.decode_to_bytes(signature)?;
+
+        match bytes.len() {
+            65 => Ok(Signature::from_raw(&bytes)?),
+            64 => Ok(Signature::from_erc2098(&bytes)),
+            r => Err(format!("[signature]: expected 64 or 65 bytes, got {}", r).into()),
+        }
+    }
+
+    pub fn normalize_v(&self, v: u64) -> Result<u8, Error> {
+        match normalize_v(v) {
+            Some(r) => Ok(27 + r as u8),
+            _ => Err(format!("[signature]: invalid v {}", v).into()),
+        }
+    }
+}
+
+impl<P> EtherSignatureAdapter<P>
+where
+    P: EtherCallPort<Output = String, Error = Error>
+        + EtherChainIdPort<Output = String, Error = Error>
+        + EtherGetCodePort<Output = String, Error = Error>,
+{
+    async fn is_contract(&self, address: &Address) -> Result<bool, Error> {
+        let code = self.provider.eth_get_code(*address, None).await?;
+
+        Ok(is_contract_code(&code))
+    }
+
+    async fn call<C: SolCall>(&self, address: &Address, call: C) -> Result<C::Return, Error> {
+        let calldata = self.ether_hash.encode_to_string(&call.abi_encode())?;
+
+        let data = self.provider.eth_call(*address, &calldata, None).await?;
+
+        let bytes = self.ether_hash.decode_to_bytes(&data)?;
+
+        Ok(C::abi_decode_returns(&bytes)?)
+    }
+
+    /*
+        EIP-1271, a revert counts as an invalid signature.
+    */
+    async fn is_valid_signature(&self, address: &Address, hash: &B256, signature: Bytes) -> bool {
+        let call = ERC1271::isValidSignatureCall {
+            hash: *hash,
+            signature,
+        };
+
+        self.call(address, call)
+            .await
+            .is_ok_and(|r| r.0 == ERC1271_MAGIC_VALUE)
+    }
+
+    /*
+        ERC-6492 for a not yet deployed wallet: the factory call and `isValidSignature` run
+        in one Multicall3 `eth_call`, so the check sees the simulated deployment.
+    */
+    async fn is_valid_counterfactual_signature(
+        &self,
+        address: &Address,
+        hash: &B256,
+        wrapped: &[u8],
+    ) -> Result<bool, Error> {
+        let (factory, factory_calldata, signature) =
+            <(Address, Bytes, Bytes)>::abi_decode_params(wrapped)?;
+
+        if self.is_contract(address).await? {
+            return Ok(self.is_valid_signature(address, hash, signature).await);
+        }
+
+        let chain_id = self.provider.eth_chain_id().await?.parse::<u64>()?;
+        let multicall = multicall3_address(chain_id).parse::<Address>()?;
+
+        let validate = ERC1271::isValidSignatureCall {
+            hash: *hash,
+            signature,
+        };
+
+        let call = Multicall3::aggregate3Call {
+            calls: vec![
+                Multicall3::Call3 {
+                    target: factory,
+                    allowFailure: true,
+                    callData: factory_calldata,
+                },
+                Multicall3::Call3 {
+                    target: *address,
+                    allowFailure: true,
+                    callData: Bytes::from(validate.abi_encode()),
+                },
+            ],
+        };
+
+        let results = self.call(&multicall, call).await?;
+
+        Ok(results.get(1).is_some_and(|r| {
+            r.success
+                && ERC1271::isValidSignatureCall::abi_decode_returns(&r.returnData)
+                    .is_ok_and(|r| r.0 == ERC1271_MAGIC_VALUE)
+        }))
+    }
+}
+
+impl<P> EtherSignaturePort for EtherSignatureAdapter<P>
+where
+    P: EtherCallPort<Output = String, Error = Error>
+        + EtherChainIdPort<Output = String, Error = Error>
+        + EtherGetCodePort<Output = String, Error = Error>,
+{
+    type Error = Error;
+
+    fn split_signature(&self, signature: &str) -> Result<EtherSignatureParts, Self::Error> {
+        let signature = self.parse_signature(signature)?;
+
+        Ok(EtherSignatureParts {
+            r: signature.r().into(),
+            s: signature.s().into(),
+            v: 27 + signature.v() as u8,
+        })
+    }
+
+    fn join_signature(&self, parts: &EtherSignatureParts) -> Result<String, Self::Error> {
+        let v = normalize_v(parts.v as u64)
+            .ok_or_else(|| format!("[signature]: invalid v {}", parts.v))?;
+
+        let signature = Signature::from_scalars_and_parity(parts.r, parts.s, v);
+
+        self.ether_hash.encode_to_string(&signature.as_bytes())
+    }
+
+    /*
+        EIP-2098 64 bytes form, `v` folded into the top bit of `s`.
+    */
+    fn compact_signature(&self, signature: &str) -> Result<String, Self::Error> {
+        let signature = self.parse_signature(signature)?;
+
+        self.ether_hash.encode_to_string(&signature.as_erc2098())
+    }
+
+    /*
+        EIP-191 `personal_sign` message, as signed by `use_ether_personal_sign`.
+    */
+    fn recover_message(&self, message: &[u8], signature: &str) -> Result<Address, Self::Error> {
+        self.recover_hash(&eip191_hash_message(message), signature)
+    }
+
+    /*
+        Prehashed digest, e.g. the EIP-712 `signing_hash`.
+    */
+    fn recover_hash(&self, hash: &B256, signature: &str) -> Result<Address, Self::Error> {
+        let signature = self.parse_signature(signature)?;
+
+        Ok(signature.recover_address_from_prehash(hash)?)
+    }
+
+    async fn verify_message(
+        &self,
+        address: &Address,
+        message: &[u8],
+        signature: &str,
+    ) -> Result<bool, Self::Error> {
+        self.verify_hash(address, &eip191_hash_message(message), signature)
+            .await
+    }
+
+    /*
+        ERC-6492 wrapped signatures first, then EIP-1271 for deployed contracts,
+        plain `ecrecover` for everything else.
+    */
+    async fn verify_hash(
+        &self,
+        address: &Address,
+        hash: &B256,
+        signature: &str,
+    ) -> Result<bool, Self::Error> {
+        let bytes = self.ether_hash.decode_to_bytes(signature)?;
+
+        if let Some(wrapped) = bytes.strip_suffix(&ERC6492_MAGIC_SUFFIX) {
+            return self
+                .is_valid_counterfactual_signature(address, hash, wrapped)
+                .await;
+        }
+
+        if self.is_contract(address).await? {
+            return Ok(self
+                .is_valid_signature(address, hash, Bytes::from(bytes))
+                .await);
+        }
+
+        Ok(self
+            .recover_hash(hash, signature)
+            .is_ok_and(|r| r == *address))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::block_on;
+    use alloy_primitives::{FixedBytes, address, b256, hex};
+
+    // web3.js `sign("Some data", privateKey)` example, key 0x4c0883a6...3f362318;
+    const SIGNER: Address = address!("0x2c7536E3605D9C16a7a3D7b1898e529396a65c23");
+    const MESSAGE: &str = "Some data";
+    const SIGNATURE: &str = "0xb91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c";
+    const COMPACT: &str = "0xb91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fde007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a029";
+
+    const WALLET: Address = address!("0x000000000000000000000000000000000000beef");
+
+    /*
+        EOAs have no code, `WALLET` is an EIP-1271 contract accepting any signature.
+    */
+    struct StubChain;
+
+    impl EtherCallPort for StubChain {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_call(
+            &self,
+            address: Address,
+            _calldata: &str,
+            _block: Option<String>,
+        ) -> Result<Self::Output, Self::Error> {
+            match address == WALLET {
+                true => Ok(hex::encode_prefixed(
+                    FixedBytes(ERC1271_MAGIC_VALUE).abi_encode(),
+                )),
+                false => Ok(String::from("0x")),
+            }
+        }
+    }
+
+    impl EtherChainIdPort for StubChain {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_chain_id(&self) -> Result<Self::Output, Self::Error> {
+            Ok(String::from("1"))
+        }
+    }
+
+    impl EtherGetCodePort for StubChain {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_get_code(
+            &self,
+            address: Address,
+            _block: Option<String>,
+        ) -> Result<Self::Output, Self::Error> {
+            match address == WALLET {
+                true => Ok(String::from("0x6080")),
+                false => Ok(String::from("0x")),
+            }
+        }
+    }
+
+    fn adapter() -> EtherSignatureAdapter<StubChain> {
+        EtherSignatureAdapter::with_provider(StubChain)
+    }
+
+    fn with_v(signature: &str, v: u8) -> String {
+        format!("{}{:02x}", &signature[..signature.len() - 2], v)
+    }
+
+    #[test]
+    fn recovers_eip191_message() {
+        assert_eq!(
+            eip191_hash_message(MESSAGE),
+            b256!("0x1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655")
+        );
+        assert_eq!(
+            adapter()
+                .recover_message(MESSAGE.as_bytes(), SIGNATURE)
+                .unwrap(),
+            SIGNER
+        );
+    }
+
+    #[test]
+    fn accepts_v_forms() {
+        for v in [1, 28] {
+            assert_eq!(
+                adapter()
+                    .recover_message(MESSAGE.as_bytes(), &with_v(SIGNATURE, v))
+                    .unwrap(),
+                SIGNER
+            );
+        }
+
+        for v in [0, 27] {
+            assert_ne!(
+                adapter()
+                    .recover_message(MESSAGE.as_bytes(), &with_v(SIGNATURE, v))
+                    .ok(),
+                Some(SIGNER)
+            );
+        }
+
+        assert_eq!(adapter().normalize_v(0).unwrap(), 27);
+        assert_eq!(adapter().normalize_v(1).unwrap(), 28);
+        assert_eq!(adapter().normalize_v(27).unwrap(), 27);
+        assert_eq!(adapter().normalize_v(28).unwrap(), 28);
+        assert_eq!(adapter().normalize_v(38).unwrap(), 28);
+        assert!(adapter().normalize_v(2).is_err());
+    }
+
+    #[test]
+    fn converts_eip2098_compact() {
+        assert_eq!(adapter().compact_signature(SIGNATURE).unwrap(), COMPACT);
+        assert_eq!(
+            adapter()
+                .recover_message(MESSAGE.as_bytes(), COMPACT)
+                .unwrap(),
+            SIGNER
+        );
+        assert_eq!(
+            adapter().split_signature(COMPACT).unwrap(),
+            adapter().split_signature(SIGNATURE).unwrap()
+        );
+    }
+
+    #[test]
+    fn splits_and_joins() {
+        let parts = adapter().split_signature(&with_v(SIGNATURE, 1)).unwrap();
+
+        assert_eq!(parts.v, 28);
+        assert_eq!(
+            parts.r,
+            b256!("0xb91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd")
+        );
+        assert_eq!(adapter().join_signature(&parts).unwrap(), SIGNATURE);
+        assert!(
+            adapter()
+                .join_signature(&EtherSignatureParts { v: 2, ..parts })
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_length() {
+        let error = adapter()
+            .recover_message(MESSAGE.as_bytes(), &SIGNATURE[..SIGNATURE.len() - 4])
+            .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .contains("expected 64 or 65 bytes, got 63")
+        );
+        assert!(adapter().split_signature("0x").is_err());
+    }
+
+    #[test]
+    fn verifies_eoa_and_contract_signers() {
+        let verify = |address| {
+            block_on(adapter().verify_message(&address, MESSAGE.as_bytes(), SIGNATURE)).unwrap()
+        };
+
+        assert!(verify(SIGNER));
+        assert!(!verify(address!(
+            "0x0000000000000000000000000000000000000001"
+        )));
+        assert!(verify(WALLET));
+    }
+}
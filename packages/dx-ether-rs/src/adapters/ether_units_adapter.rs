@@ -0,0 +1,329 @@
+use alloy_primitives::U256;
+use std::fmt;
+
+use crate::{
+    errors::{error::Error, ether_units_error::EtherUnitsError},
+    ports::ether_units_port::EtherUnitsPort,
+    types::ether_units_types::EtherUnitsFormat,
+};
+
+// 10^77 is the largest power of ten that fits into uint256;
+const MAX_DECIMALS: u8 = 77;
+
+const COMPACT_SUFFIXES: [&str; 5] = ["", "K", "M", "B", "T"];
+
+pub struct EtherUnitsAdapter;
+
+impl Default for EtherUnitsAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EtherUnitsAdapter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn base(&self, decimals: u8) -> Result<U256, Error> {
+        match decimals > MAX_DECIMALS {
+            true => Err(EtherUnitsError::Overflow(format!("10^{}", decimals)).into()),
+            false => Ok(U256::from(10).pow(U256::from(decimals))),
+        }
+    }
+
+    /*
+        Round half up to `precision` fraction digits, returns the integer and the fraction digits.
+    */
+    fn split(&self, value: U256, decimals: u8, precision: u8) -> Result<(U256, String), Error> {
+        let precision = precision.min(decimals);
+        let scale = self.base(decimals - precision)?;
+
+        let mut scaled = value / scale;
+
+        if precision < decimals && value % scale >= scale / U256::from(2) {
+            scaled = scaled.saturating_add(U256::from(1));
+        }
+
+        let base = self.base(precision)?;
+
+        let fraction = match precision {
+            0 => String::new(),
+            _ => format!("{:0>width$}", scaled % base, width = precision as usize),
+        };
+
+        Ok((scaled / base, fraction.trim_end_matches('0').to_string()))
+    }
+
+    fn group(&self, integer: &str) -> String {
+        let mut grouped = String::with_capacity(integer.len() + integer.len() / 3);
+
+        for (i, r) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i).is_multiple_of(3) {
+                grouped.push(',');
+            }
+
+            grouped.push(r);
+        }
+
+        grouped
+    }
+}
+
+impl EtherUnitsPort for EtherUnitsAdapter {
+    type Error = Error;
+
+    /*
+        Exact decimal string to integer units, e.g. ("1.5", 18) -> 1500000000000000000.
+    */
+    fn parse_units(&self, value: &str, decimals: u8) -> Result<U256, Self::Error> {
+        let value = value.trim();
+
+        let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+
+        let digits = |r: &str| r.bytes().all(|b| b.is_ascii_digit());
+
+        if (integer.is_empty() && fraction.is_empty()) || !digits(integer) || !digits(fraction) {
+            return Err(EtherUnitsError::InvalidNumber(value.to_string()).into());
+        }
+
+        let fraction = fraction.trim_end_matches('0');
+
+        if fraction.len() > decimals as usize {
+            return Err(EtherUnitsError::TooManyDecimals {
+                value: value.to_string(),
+                decimals,
+            }
+            .into());
+        }
+
+        let overflow = || EtherUnitsError::Overflow(value.to_string());
+
+        let integer = match integer.is_empty() {
+            true => U256::ZERO,
+            false => U256::from_str_radix(integer, 10).map_err(|_| overflow())?,
+        };
+
+        let fraction = match fraction.is_empty() {
+            true => U256::ZERO,
+            false => {
+                U256::from_str_radix(fraction, 10)? * self.base(decimals - fraction.len() as u8)?
+            }
+        };
+
+        Ok(integer
+            .checked_mul(self.base(decimals)?)
+            .and_then(|r| r.checked_add(fraction))
+            .ok_or_else(overflow)?)
+    }
+
+    /*
+        Exact integer units to decimal string, trailing zeros are trimmed.
+    */
+    fn format_units(&self, value: U256, decimals: u8) -> Result<String, Self::Error> {
+        let (integer, fraction) = self.split(value, decimals, decimals)?;
+
+        match fraction.is_empty() {
+            true => Ok(integer.to_string()),
+            false => Ok(format!("{}.{}", integer, fraction)),
+        }
+    }
+
+    fn format_with(
+        &self,
+        value: U256,
+        decimals: u8,
+        options: &EtherUnitsFormat,
+    ) -> Result<String, Self::Error> {
+        let mut suffix = 0;
+        let mut precision = options.precision.unwrap_or(decimals);
+
+        if options.compact {
+            precision = options.precision.unwrap_or(1);
+
+            let digits = (value / self.base(decimals)?).to_string().len();
+
+            suffix = ((digits - 1) / 3).min(COMPACT_SUFFIXES.len() - 1);
+        }
+
+        let (mut integer, mut fraction) =
+            self.split(value, decimals.saturating_add(suffix as u8 * 3), precision)?;
+
+        // 999.96 rounds to 1000.0 and 999.96K to 1000.0K, which read as 1K and 1M;
+        if options.compact && suffix < COMPACT_SUFFIXES.len() - 1 && integer >= U256::from(1000) {
+            suffix += 1;
+            (integer, fraction) = self.split(value, decimals + suffix as u8 * 3, precision)?;
+        }
+
+        let integer = match options.grouping {
+            true => self.group(&integer.to_string()),
+            false => integer.to_string(),
+        };
+
+        match fraction.is_empty() {
+            true => Ok(format!("{}{}", integer, COMPACT_SUFFIXES[suffix])),
+            false => Ok(format!(
+                "{}.{}{}",
+                integer, fraction, COMPACT_SUFFIXES[suffix]
+            )),
+        }
+    }
+}
+
+/*
+    Typed ether amounts, all of them hold the value in wei and differ in parsing and display.
+*/
+macro_rules! ether_unit {
+    ($name:ident, $decimals:expr) => {
+        #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Debug)]
+        pub struct $name(pub U256);
+
+        impl $name {
+            pub const DECIMALS: u8 = $decimals;
+
+            pub fn parse(value: &str) -> Result<Self, Error> {
+                Ok(Self(
+                    EtherUnitsAdapter::new().parse_units(value, Self::DECIMALS)?,
+                ))
+            }
+
+            pub fn from_wei(wei: U256) -> Self {
+                Self(wei)
+            }
+
+            pub fn wei(&self) -> U256 {
+                self.0
+            }
+
+            pub fn format(&self, options: &EtherUnitsFormat) -> Result<String, Error> {
+                EtherUnitsAdapter::new().format_with(self.0, Self::DECIMALS, options)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let value = EtherUnitsAdapter::new()
+                    .format_units(self.0, Self::DECIMALS)
+                    .map_err(|_| fmt::Error)?;
+
+                write!(f, "{}", value)
+            }
+        }
+    };
+}
+
+macro_rules! ether_unit_from {
+    ($from:ident => $($to:ident),+) => {
+        $(
+            impl From<$from> for $to {
+                fn from(value: $from) -> Self {
+                    Self(value.0)
+                }
+            }
+        )+
+    };
+}
+
+ether_unit!(Wei, 0);
+ether_unit!(Gwei, 9);
+ether_unit!(Ether, 18);
+
+ether_unit_from!(Wei => Gwei, Ether);
+ether_unit_from!(Gwei => Wei, Ether);
+ether_unit_from!(Ether => Wei, Gwei);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ether(value: &str) -> U256 {
+        EtherUnitsAdapter::new().parse_units(value, 18).unwrap()
+    }
+
+    fn compact(value: &str) -> String {
+        Ether(ether(value))
+            .format(&EtherUnitsFormat {
+                compact: true,
+                ..Default::default()
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn parses_exact_units() {
+        let units = EtherUnitsAdapter::new();
+
+        assert_eq!(
+            units.parse_units("1.5", 18).unwrap(),
+            U256::from(1_500_000_000_000_000_000u128)
+        );
+        assert_eq!(units.parse_units(" .5 ", 1).unwrap(), U256::from(5));
+        assert_eq!(units.parse_units("2.", 0).unwrap(), U256::from(2));
+        assert_eq!(units.parse_units("1.2300", 2).unwrap(), U256::from(123));
+    }
+
+    #[test]
+    fn rejects_invalid_units() {
+        let units = EtherUnitsAdapter::new();
+
+        assert_eq!(
+            units.parse_units("1.001", 2).unwrap_err().to_string(),
+            EtherUnitsError::TooManyDecimals {
+                value: String::from("1.001"),
+                decimals: 2,
+            }
+            .to_string()
+        );
+
+        for invalid in ["-1", "1e18", "1,000", "0x10", "", "."] {
+            assert_eq!(
+                units.parse_units(invalid, 18).unwrap_err().to_string(),
+                EtherUnitsError::InvalidNumber(invalid.to_string()).to_string()
+            );
+        }
+
+        assert!(units.parse_units(&"9".repeat(78), 0).is_err());
+        assert!(units.parse_units("1", 78).is_err());
+    }
+
+    #[test]
+    fn formats_trimmed_units() {
+        let units = EtherUnitsAdapter::new();
+
+        assert_eq!(units.format_units(ether("1.5"), 18).unwrap(), "1.5");
+        assert_eq!(units.format_units(ether("1"), 18).unwrap(), "1");
+        assert_eq!(units.format_units(U256::ZERO, 18).unwrap(), "0");
+        assert_eq!(
+            units.format_units(U256::from(1), 18).unwrap(),
+            "0.000000000000000001"
+        );
+        assert!(units.format_units(U256::from(1), 78).is_err());
+    }
+
+    #[test]
+    fn formats_with_precision_and_grouping() {
+        let format = EtherUnitsFormat {
+            precision: Some(1),
+            grouping: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Ether(ether("1234567.25")).format(&format).unwrap(),
+            "1,234,567.3"
+        );
+        assert_eq!(Ether(ether("999.96")).format(&format).unwrap(), "1,000");
+        assert_eq!(Ether(ether("100")).to_string(), "100");
+    }
+
+    #[test]
+    fn formats_compact_boundaries() {
+        assert_eq!(compact("999.94"), "999.9");
+        assert_eq!(compact("999.96"), "1K");
+        assert_eq!(compact("1000"), "1K");
+        assert_eq!(compact("999949"), "999.9K");
+        assert_eq!(compact("999950"), "1M");
+        assert_eq!(compact("1234567.5"), "1.2M");
+        assert_eq!(compact("999960000000000"), "1000T");
+    }
+}
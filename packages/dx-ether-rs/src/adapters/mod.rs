@@ -1,2 +1,18 @@
+pub mod ccip_read_adapter;
+pub mod contract_adapter;
+pub mod ens_adapter;
+pub mod erc1155_adapter;
+pub mod erc165_adapter;
+pub mod erc20_adapter;
+pub mod erc721_adapter;
 pub mod ether_adapter;
+pub mod ether_address_adapter;
+pub mod ether_calls_adapter;
+pub mod ether_chain_adapter;
 pub mod ether_hash_adapter;
+pub mod ether_signature_adapter;
+pub mod ether_units_adapter;
+pub mod multicall_adapter;
+pub mod nft_metadata_adapter;
+pub mod siwe_adapter;
+pub mod user_operation_adapter;
@@ -0,0 +1,377 @@
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::SolCall;
+
+use crate::{
+    abi::multicall3_abi::Multicall3,
+    adapters::{
+        ether_adapter::EtherAdapter,
+        ether_address_adapter::parse_address,
+        ether_chain_adapter::{ChainId, known_chain, update_chain},
+        ether_hash_adapter::EtherHashAdapter,
+    },
+    constants::multicall3_constants::MULTICALL3_ADDRESS,
+    errors::error::Error,
+    ports::{
+        ether::{ether_call_port::EtherCallPort, ether_chain_id_port::EtherChainIdPort},
+        ether_hash_port::EtherHashPort,
+        multicall_port::MulticallPort,
+    },
+    types::multicall_types::MulticallCalls,
+};
+
+/*
+    Override the Multicall3 address used on a known chain, e.g. for a local devnet deployment.
+*/
+pub fn set_multicall3_address(chain_id: u64, address: &str) -> Result<(), Error> {
+    update_chain(ChainId(chain_id), |r| {
+        r.multicall3 = Some(address.to_string())
+    })
+}
+
+pub fn multicall3_address(chain_id: u64) -> String {
+    known_chain(ChainId(chain_id))
+        .map(|r| r.multicall3_address())
+        .unwrap_or(String::from(MULTICALL3_ADDRESS))
+}
+
+pub struct MulticallAdapter<P = EtherAdapter> {
+    address: Option<String>,
+    provider: P,
+    ether_hash: EtherHashAdapter,
+}
+
+impl Default for MulticallAdapter<EtherAdapter> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MulticallAdapter<EtherAdapter> {
+    pub fn new() -> Self {
+        Self::with_provider(EtherAdapter::new())
+    }
+}
+
+impl<P> MulticallAdapter<P> {
+    pub fn with_provider(provider: P) -> Self {
+        Self {
+            address: None,
+            provider,
+            ether_hash: EtherHashAdapter::new(),
+        }
+    }
+
+    /*
+        Fixed Multicall3 address, otherwise it is picked by the wallet chain id.
+    */
+    pub fn address(mut self, address: &str) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+}
+
+impl<P> MulticallAdapter<P>
+where
+    P: EtherCallPort<Output = String, Error = Error>
+        + EtherChainIdPort<Output = String, Error = Error>,
+{
+    async fn resolve_address(&self) -> Result<Address, Error> {
+        if let Some(address) = &self.address {
+            return parse_address(address);
+        }
+
+        let chain_id = self.provider.eth_chain_id().await?.parse::<u64>()?;
+
+        parse_address(&multicall3_address(chain_id))
+    }
+
+    async fn call<C: SolCall>(&self, call: C, block: Option<String>) -> Result<C::Return, Error> {
+        let address = self.resolve_address().await?;
+        let calldata = self.ether_hash.encode_to_string(&call.abi_encode())?;
+
+        let data = self.provider.eth_call(address, &calldata, block).await?;
+
+        let bytes = self.ether_hash.decode_to_bytes(&data)?;
+
+        Ok(C::abi_decode_returns(&bytes)?)
+    }
+
+    fn decode<T: MulticallCalls>(
+        &self,
+        calls: &T,
+        results: Vec<Multicall3::Result>,
+    ) -> Result<T::Returns, Error> {
+        if results.len() != calls.len() {
+            return Err(format!(
+                "[multicall]: expected {} results, got {}",
+                calls.len(),
+                results.len()
+            )
+            .into());
+        }
+
+        Ok(calls.decode(&results))
+    }
+}
+
+impl<P> MulticallPort for MulticallAdapter<P>
+where
+    P: EtherCallPort<Output = String, Error = Error>
+        + EtherChainIdPort<Output = String, Error = Error>,
+{
+    type Error = Error;
+
+    async fn aggregate3<T: MulticallCalls>(
+        &self,
+        calls: &T,
+        block: Option<String>,
+    ) -> Result<T::Returns, Self::Error> {
+        let call = Multicall3::aggregate3Call {
+            calls: calls.encode()?,
+        };
+
+        let results = self.call(call, block).await?;
+
+        self.decode(calls, results)
+    }
+
+    /*
+        Per-call `allow_failure` is ignored, `require_success` applies to the whole batch.
+    */
+    async fn try_aggregate<T: MulticallCalls>(
+        &self,
+        require_success: bool,
+        calls: &T,
+        block: Option<String>,
+    ) -> Result<T::Returns, Self::Error> {
+        let call = Multicall3::tryAggregateCall {
+            requireSuccess: require_success,
+            calls: calls
+                .encode()?
+                .into_iter()
+                .map(|r| Multicall3::Call {
+                    target: r.target,
+                    callData: r.callData,
+                })
+                .collect(),
+        };
+
+        let results = self.call(call, block).await?;
+
+        self.decode(calls, results)
+    }
+
+    async fn get_eth_balance(
+        &self,
+        address: Address,
+        block: Option<String>,
+    ) -> Result<U256, Self::Error> {
+        let call = Multicall3::getEthBalanceCall { addr: address };
+
+        self.call(call, block).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        abi::erc20_abi::ERC20,
+        adapters::ether_chain_adapter::{EtherChain, register_chain},
+        test_utils::block_on,
+        types::multicall_types::MulticallCall,
+    };
+    use alloy_primitives::{Bytes, address, hex};
+    use alloy_sol_types::{Revert, SolError, SolValue};
+    use std::sync::Mutex;
+
+    const TOKEN: Address = address!("0x6b175474e89094c44da98b954eedeac495271d0f");
+    const OWNER: Address = address!("0x1111111111111111111111111111111111111111");
+    const DEVNET_MULTICALL3: Address = address!("0x2222222222222222222222222222222222222222");
+
+    /*
+        Multicall3 answering every batch with the same results, calls are kept to check
+        the encoded batch and the contract address.
+    */
+    struct StubMulticall {
+        chain_id: String,
+        results: Vec<Multicall3::Result>,
+        calls: Mutex<Vec<(Address, Bytes)>>,
+    }
+
+    impl StubMulticall {
+        fn new(results: Vec<Multicall3::Result>) -> Self {
+            Self {
+                chain_id: String::from("1"),
+                results,
+                calls: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn calls(&self) -> Vec<(Address, Bytes)> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    impl EtherCallPort for StubMulticall {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_call(
+            &self,
+            address: Address,
+            calldata: &str,
+            _block: Option<String>,
+        ) -> Result<Self::Output, Self::Error> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push((address, hex::decode(calldata)?.into()));
+
+            Ok(hex::encode_prefixed(
+                Multicall3::aggregate3Call::abi_encode_returns(&self.results),
+            ))
+        }
+    }
+
+    impl EtherChainIdPort for StubMulticall {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_chain_id(&self) -> Result<Self::Output, Self::Error> {
+            Ok(self.chain_id.clone())
+        }
+    }
+
+    fn success(data: Vec<u8>) -> Multicall3::Result {
+        Multicall3::Result {
+            success: true,
+            returnData: data.into(),
+        }
+    }
+
+    fn failure(reason: &str) -> Multicall3::Result {
+        Multicall3::Result {
+            success: false,
+            returnData: Revert::from(reason).abi_encode().into(),
+        }
+    }
+
+    #[test]
+    fn aggregates_tuple_of_calls() {
+        let adapter = MulticallAdapter::with_provider(StubMulticall::new(vec![
+            success(U256::from(42).abi_encode()),
+            success(U256::from(18).abi_encode()),
+            failure("no decimals"),
+        ]));
+
+        let calls = (
+            MulticallCall::new(TOKEN, ERC20::balanceOfCall { owner: OWNER }).require_success(),
+            vec![
+                MulticallCall::new(TOKEN, ERC20::decimalsCall {}),
+                MulticallCall::new(OWNER, ERC20::decimalsCall {}),
+            ],
+        );
+
+        let (balance, decimals) = block_on(adapter.aggregate3(&calls, None)).unwrap();
+
+        assert_eq!(balance.unwrap(), U256::from(42));
+        assert_eq!(decimals[0].as_ref().unwrap(), &18);
+        assert_eq!(
+            decimals[1].as_ref().unwrap_err().revert_reason().as_deref(),
+            Some("no decimals")
+        );
+
+        let (address, calldata) = adapter.provider.calls().remove(0);
+        let batch = Multicall3::aggregate3Call::abi_decode(&calldata).unwrap();
+
+        assert_eq!(address, MULTICALL3_ADDRESS.parse::<Address>().unwrap());
+        assert_eq!(
+            batch
+                .calls
+                .iter()
+                .map(|r| (r.target, r.allowFailure))
+                .collect::<Vec<_>>(),
+            vec![(TOKEN, false), (TOKEN, true), (OWNER, true)]
+        );
+        assert_eq!(
+            batch.calls[0].callData,
+            Bytes::from(ERC20::balanceOfCall { owner: OWNER }.abi_encode())
+        );
+    }
+
+    #[test]
+    fn rejects_result_count_mismatch() {
+        let adapter = MulticallAdapter::with_provider(StubMulticall::new(vec![success(
+            U256::from(18).abi_encode(),
+        )]));
+
+        let calls = vec![
+            MulticallCall::new(TOKEN, ERC20::decimalsCall {}),
+            MulticallCall::new(TOKEN, ERC20::decimalsCall {}),
+        ];
+
+        let error = block_on(adapter.aggregate3(&calls, None)).unwrap_err();
+
+        assert!(error.to_string().contains("expected 2 results, got 1"));
+    }
+
+    #[test]
+    fn try_aggregate_drops_allow_failure() {
+        let adapter = MulticallAdapter::with_provider(StubMulticall::new(vec![
+            success(U256::from(18).abi_encode()),
+            failure("paused"),
+        ]));
+
+        let calls = vec![
+            MulticallCall::new(TOKEN, ERC20::decimalsCall {}).require_success(),
+            MulticallCall::new(OWNER, ERC20::decimalsCall {}),
+        ];
+
+        let results = block_on(adapter.try_aggregate(false, &calls, None)).unwrap();
+
+        assert_eq!(results[0].as_ref().unwrap(), &18);
+        assert!(results[1].is_err());
+
+        let (_, calldata) = adapter.provider.calls().remove(0);
+        let batch = Multicall3::tryAggregateCall::abi_decode(&calldata).unwrap();
+
+        assert!(!batch.requireSuccess);
+        assert_eq!(
+            batch.calls.iter().map(|r| r.target).collect::<Vec<_>>(),
+            vec![TOKEN, OWNER]
+        );
+    }
+
+    #[test]
+    fn picks_address_per_chain() {
+        register_chain(EtherChain::new(ChainId(990_036), "Multicall devnet")).unwrap();
+        set_multicall3_address(990_036, &DEVNET_MULTICALL3.to_string()).unwrap();
+
+        assert!(set_multicall3_address(990_037, &DEVNET_MULTICALL3.to_string()).is_err());
+
+        let provider = StubMulticall {
+            chain_id: String::from("990036"),
+            ..StubMulticall::new(Vec::new())
+        };
+        let adapter = MulticallAdapter::with_provider(provider);
+
+        block_on(adapter.aggregate3(&Vec::<MulticallCall<ERC20::decimalsCall>>::new(), None))
+            .unwrap();
+
+        assert_eq!(adapter.provider.calls()[0].0, DEVNET_MULTICALL3);
+
+        // A fixed address wins over the chain registry;
+        let adapter = MulticallAdapter::with_provider(StubMulticall {
+            chain_id: String::from("990036"),
+            ..StubMulticall::new(Vec::new())
+        })
+        .address(&TOKEN.to_string());
+
+        block_on(adapter.aggregate3(&Vec::<MulticallCall<ERC20::decimalsCall>>::new(), None))
+            .unwrap();
+
+        assert_eq!(adapter.provider.calls()[0].0, TOKEN);
+        assert_eq!(multicall3_address(990_037), MULTICALL3_ADDRESS);
+    }
+}
@@ -0,0 +1,494 @@
+use alloy_primitives::U256;
+use base64::{
+    Engine, alphabet,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig, general_purpose::STANDARD},
+};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{LazyLock, Mutex},
+};
+
+use crate::{
+    adapters::ether_adapter::EtherAdapter,
+    errors::error::Error,
+    ports::{http_get_port::HttpGetPort, nft_metadata_port::NftMetadataPort},
+    types::nft_types::NftMetadata,
+};
+
+/*
+    Metadata is keyed by the token URI, token URIs are not expected to change.
+    Oldest entries are dropped past the limit, `data:` URIs are never cached.
+*/
+static NFT_METADATA: LazyLock<Mutex<NftMetadataCache>> =
+    LazyLock::new(|| Mutex::new(NftMetadataCache::default()));
+
+const NFT_METADATA_CACHE_LIMIT: usize = 512;
+
+#[derive(Default)]
+struct NftMetadataCache {
+    entries: HashMap<String, NftMetadata>,
+    order: VecDeque<String>,
+}
+
+impl NftMetadataCache {
+    fn get(&self, uri: &str) -> Option<NftMetadata> {
+        self.entries.get(uri).cloned()
+    }
+
+    fn insert(&mut self, uri: String, metadata: NftMetadata) {
+        if self.entries.insert(uri.clone(), metadata).is_some() {
+            return;
+        }
+
+        self.order.push_back(uri);
+
+        while self.order.len() > NFT_METADATA_CACHE_LIMIT {
+            if let Some(r) = self.order.pop_front() {
+                self.entries.remove(&r);
+            }
+        }
+    }
+}
+
+/*
+    On-chain base64 is often emitted without padding.
+*/
+const BASE64: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/*
+    Gateways are tried in order, the next one is used when a fetch fails.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct NftGateways {
+    pub ipfs: Vec<String>,
+    pub arweave: Vec<String>,
+}
+
+impl Default for NftGateways {
+    fn default() -> Self {
+        Self {
+            ipfs: vec![
+                String::from("https://ipfs.io/ipfs/"),
+                String::from("https://dweb.link/ipfs/"),
+            ],
+            arweave: vec![String::from("https://arweave.net/")],
+        }
+    }
+}
+
+impl NftGateways {
+    /*
+        `ipfs://<cid>`, `ipfs://ipfs/<cid>` and `ar://<id>` become gateway URLs, others pass through.
+    */
+    pub fn resolve_urls(&self, uri: &str) -> Vec<String> {
+        let uri = uri.trim();
+
+        if let Some(path) = uri.strip_prefix("ipfs://") {
+            let path = path.trim_start_matches("ipfs/");
+
+            return self.ipfs.iter().map(|r| format!("{}{}", r, path)).collect();
+        }
+
+        if let Some(path) = uri.strip_prefix("ar://") {
+            return self
+                .arweave
+                .iter()
+                .map(|r| format!("{}{}", r, path))
+                .collect();
+        }
+
+        vec![uri.to_string()]
+    }
+
+    /*
+        URL through the first gateway, e.g. for an `img` src.
+    */
+    pub fn resolve_url(&self, uri: &str) -> String {
+        self.resolve_urls(uri)
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| uri.trim().to_string())
+    }
+}
+
+/*
+    ERC-1155 `{id}`: lowercase hex token id, zero padded to 64 chars, no `0x`.
+*/
+pub fn substitute_id(uri: &str, token_id: U256) -> String {
+    uri.replace("{id}", &format!("{:064x}", token_id))
+}
+
+/*
+    Returns the mime type and payload of a `data:` URI, `None` for other URIs.
+*/
+pub fn decode_data_uri(uri: &str) -> Result<Option<(String, Vec<u8>)>, Error> {
+    let Some(rest) = uri.trim().strip_prefix("data:") else {
+        return Ok(None);
+    };
+
+    let (meta, payload) = rest
+        .split_once(',')
+        .ok_or("[data uri]: missing payload separator")?;
+
+    let mut params = meta.split(';');
+
+    let mime = match params.next().map(str::trim) {
+        Some(r) if !r.is_empty() => r.to_lowercase(),
+        _ => String::from("text/plain"),
+    };
+
+    let payload = match params.any(|r| r.trim().eq_ignore_ascii_case("base64")) {
+        true => BASE64.decode(percent_decode(payload.trim()))?,
+        false => percent_decode(payload),
+    };
+
+    Ok(Some((mime, payload)))
+}
+
+fn percent_decode(value: &str) -> Vec<u8> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|r| std::str::from_utf8(r).ok())
+            .and_then(|r| u8::from_str_radix(r, 16).ok());
+
+        match escaped {
+            Some(r) => {
+                decoded.push(r);
+                i += 3;
+            }
+            _ => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    decoded
+}
+
+pub struct NftMetadataAdapter<P = EtherAdapter> {
+    provider: P,
+    gateways: NftGateways,
+}
+
+impl Default for NftMetadataAdapter<EtherAdapter> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NftMetadataAdapter<EtherAdapter> {
+    pub fn new() -> Self {
+        Self::with_provider(EtherAdapter::new(), NftGateways::default())
+    }
+}
+
+impl<P> NftMetadataAdapter<P> {
+    pub fn with_provider(provider: P, gateways: NftGateways) -> Self {
+        Self { provider, gateways }
+    }
+
+    pub fn gateways(&self) -> &NftGateways {
+        &self.gateways
+    }
+
+    fn image_src(&self, metadata: &NftMetadata) -> Option<String> {
+        if let Some(svg) = metadata.image_data.as_ref().filter(|r| !r.is_empty()) {
+            return Some(format!(
+                "data:image/svg+xml;base64,{}",
+                STANDARD.encode(svg.as_bytes())
+            ));
+        }
+
+        metadata
+            .image
+            .as_ref()
+            .filter(|r| !r.is_empty())
+            .map(|r| match r.starts_with("data:") {
+                true => r.to_string(),
+                false => self.gateways.resolve_url(r),
+            })
+    }
+}
+
+impl<P> NftMetadataAdapter<P>
+where
+    P: HttpGetPort<Output = String, Error = Error>,
+{
+    /*
+        A gateway that fails or answers with something other than metadata JSON
+        (e.g. an HTML error page) falls through to the next one.
+    */
+    async fn fetch(&self, uri: &str) -> Result<NftMetadata, Error> {
+        let mut error: Error = format!("[nft metadata]: no gateway for {}", uri).into();
+
+        for url in self.gateways.resolve_urls(uri) {
+            let json = match self.provider.http_get(&url).await {
+                Ok(r) => r,
+                Err(e) => {
+                    error = e;
+                    continue;
+                }
+            };
+
+            match serde_json::from_str::<NftMetadata>(&json) {
+                Ok(r) => return Ok(r),
+                Err(e) => error = e.into(),
+            }
+        }
+
+        Err(error)
+    }
+}
+
+impl<P> NftMetadataPort for NftMetadataAdapter<P>
+where
+    P: HttpGetPort<Output = String, Error = Error>,
+{
+    type Error = Error;
+
+    async fn resolve(&self, uri: &str, token_id: Option<U256>) -> Result<NftMetadata, Self::Error> {
+        let uri = match token_id {
+            Some(id) => substitute_id(uri, id),
+            _ => uri.trim().to_string(),
+        };
+
+        let mut metadata = match decode_data_uri(&uri)? {
+            Some((_, payload)) => serde_json::from_slice::<NftMetadata>(&payload)?,
+            _ => {
+                if let Some(r) = NFT_METADATA.lock().ok().and_then(|r| r.get(&uri)) {
+                    return Ok(r);
+                }
+
+                self.fetch(&uri).await?
+            }
+        };
+
+        if let (Some(id), Some(image)) = (token_id, metadata.image.as_ref()) {
+            metadata.image = Some(substitute_id(image, id));
+        }
+
+        metadata.image_src = self.image_src(&metadata);
+
+        if !uri.starts_with("data:")
+            && let Ok(mut cache) = NFT_METADATA.lock()
+        {
+            cache.insert(uri, metadata.clone());
+        }
+
+        Ok(metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::block_on;
+
+    /*
+        Local gateway stand-in: URLs without a response fail like an unreachable gateway.
+    */
+    #[derive(Default)]
+    struct StubGateway {
+        responses: HashMap<String, String>,
+        requests: Mutex<Vec<String>>,
+    }
+
+    impl StubGateway {
+        fn with(mut self, url: &str, body: &str) -> Self {
+            self.responses.insert(url.to_string(), body.to_string());
+            self
+        }
+
+        fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    impl HttpGetPort for StubGateway {
+        type Output = String;
+        type Error = Error;
+
+        async fn http_get(&self, url: &str) -> Result<Self::Output, Self::Error> {
+            self.requests.lock().unwrap().push(url.to_string());
+
+            self.responses
+                .get(url)
+                .cloned()
+                .ok_or_else(|| format!("[http get]: {} unreachable", url).into())
+        }
+    }
+
+    fn adapter(gateway: StubGateway) -> NftMetadataAdapter<StubGateway> {
+        let gateways = NftGateways {
+            ipfs: vec![
+                String::from("https://first.test/ipfs/"),
+                String::from("https://second.test/ipfs/"),
+            ],
+            arweave: vec![String::from("https://arweave.test/")],
+        };
+
+        NftMetadataAdapter::with_provider(gateway, gateways)
+    }
+
+    #[test]
+    fn resolves_gateway_urls() {
+        let gateways = NftGateways::default();
+
+        assert_eq!(
+            gateways.resolve_urls("ipfs://QmHash/1.json"),
+            vec![
+                "https://ipfs.io/ipfs/QmHash/1.json",
+                "https://dweb.link/ipfs/QmHash/1.json"
+            ]
+        );
+        assert_eq!(
+            gateways.resolve_url("ipfs://ipfs/QmHash"),
+            "https://ipfs.io/ipfs/QmHash"
+        );
+        assert_eq!(
+            gateways.resolve_url("ar://tx-id"),
+            "https://arweave.net/tx-id"
+        );
+        assert_eq!(
+            gateways.resolve_url(" https://example.com/1.json "),
+            "https://example.com/1.json"
+        );
+    }
+
+    #[test]
+    fn substitutes_erc1155_id() {
+        assert_eq!(
+            substitute_id("https://token.test/{id}.json", U256::from(0x4cce0)),
+            format!("https://token.test/{}4cce0.json", "0".repeat(59))
+        );
+    }
+
+    #[test]
+    fn decodes_data_uris() {
+        let (mime, payload) = decode_data_uri("data:application/json;base64,eyJuYW1lIjoiQSJ9")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(mime, "application/json");
+        assert_eq!(payload, br#"{"name":"A"}"#);
+
+        let (mime, payload) = decode_data_uri("data:,%7B%22name%22%3A%22B%22%7D")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(mime, "text/plain");
+        assert_eq!(payload, br#"{"name":"B"}"#);
+
+        assert!(decode_data_uri("ipfs://QmHash").unwrap().is_none());
+        assert!(decode_data_uri("data:application/json;base64").is_err());
+    }
+
+    #[test]
+    fn resolves_data_uri_without_caching() {
+        let uri = "data:application/json;utf8,{\"name\":\"On-chain\",\"image_data\":\"<svg/>\"}";
+        let adapter = adapter(StubGateway::default());
+
+        let metadata = block_on(adapter.resolve(uri, None)).unwrap();
+
+        assert_eq!(metadata.name.as_deref(), Some("On-chain"));
+        assert_eq!(
+            metadata.image_src.as_deref(),
+            Some("data:image/svg+xml;base64,PHN2Zy8+")
+        );
+        assert!(adapter.provider.requests().is_empty());
+        assert!(NFT_METADATA.lock().unwrap().get(uri).is_none());
+    }
+
+    #[test]
+    fn falls_back_to_next_gateway() {
+        let gateway = StubGateway::default()
+            .with("https://first.test/ipfs/QmFallback/1", "<html>504</html>")
+            .with(
+                "https://second.test/ipfs/QmFallback/1",
+                r#"{"name":"Fallback","image":"ar://image-id"}"#,
+            );
+        let adapter = adapter(gateway);
+
+        let metadata = block_on(adapter.resolve("ipfs://QmFallback/1", None)).unwrap();
+
+        assert_eq!(metadata.name.as_deref(), Some("Fallback"));
+        assert_eq!(
+            metadata.image_src.as_deref(),
+            Some("https://arweave.test/image-id")
+        );
+        assert_eq!(
+            adapter.provider.requests(),
+            vec![
+                "https://first.test/ipfs/QmFallback/1",
+                "https://second.test/ipfs/QmFallback/1"
+            ]
+        );
+    }
+
+    #[test]
+    fn fails_when_every_gateway_fails() {
+        let adapter = adapter(StubGateway::default());
+
+        assert!(block_on(adapter.resolve("ipfs://QmMissing", None)).is_err());
+        assert_eq!(adapter.provider.requests().len(), 2);
+    }
+
+    #[test]
+    fn substitutes_id_in_uri_and_image() {
+        let id = format!("{:064x}", 42);
+        let gateway = StubGateway::default().with(
+            &format!("https://token.test/{}.json", id),
+            r#"{"name":"Edition","image":"https://token.test/{id}.png"}"#,
+        );
+        let adapter = adapter(gateway);
+
+        let metadata =
+            block_on(adapter.resolve("https://token.test/{id}.json", Some(U256::from(42))))
+                .unwrap();
+
+        assert_eq!(
+            metadata.image.as_deref(),
+            Some(format!("https://token.test/{}.png", id).as_str())
+        );
+    }
+
+    #[test]
+    fn caches_fetched_metadata() {
+        let gateway =
+            StubGateway::default().with("https://arweave.test/cached", r#"{"name":"Cached"}"#);
+        let adapter = adapter(gateway);
+
+        let first = block_on(adapter.resolve("ar://cached", None)).unwrap();
+        let second = block_on(adapter.resolve("ar://cached", None)).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(adapter.provider.requests().len(), 1);
+    }
+
+    #[test]
+    fn bounds_the_cache() {
+        let mut cache = NftMetadataCache::default();
+
+        for i in 0..=NFT_METADATA_CACHE_LIMIT {
+            cache.insert(format!("ipfs://{}", i), NftMetadata::default());
+        }
+
+        assert_eq!(cache.entries.len(), NFT_METADATA_CACHE_LIMIT);
+        assert!(cache.get("ipfs://0").is_none());
+        assert!(
+            cache
+                .get(&format!("ipfs://{}", NFT_METADATA_CACHE_LIMIT))
+                .is_some()
+        );
+    }
+}
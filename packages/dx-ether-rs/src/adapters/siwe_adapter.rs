@@ -0,0 +1,848 @@
+use alloy_primitives::Address;
+
+use crate::{
+    adapters::{ether_adapter::EtherAdapter, ether_signature_adapter::EtherSignatureAdapter},
+    errors::{error::Error, siwe_error::SiweError},
+    ports::{
+        ether::{
+            ether_call_port::EtherCallPort, ether_chain_id_port::EtherChainIdPort,
+            ether_get_code_port::EtherGetCodePort,
+        },
+        ether_signature_port::EtherSignaturePort,
+        siwe_port::SiwePort,
+    },
+    types::siwe_types::{SiweMessage, SiweVerifyOptions},
+};
+
+const SIWE_HEADER: &str = " wants you to sign in with your Ethereum account:";
+
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146097 + doe - 719468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let doe = days - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+
+    (yoe + era * 400 + i64::from(month <= 2), month, day)
+}
+
+/*
+    RFC 3339 `date-time` to unix seconds, fractional seconds are truncated.
+*/
+pub fn parse_timestamp(value: &str) -> Result<i64, Error> {
+    let invalid = || SiweError::InvalidField {
+        field: String::from("timestamp"),
+        reason: format!("`{}` is not RFC 3339", value),
+    };
+
+    let bytes = value.as_bytes();
+
+    let number = |from: usize, to: usize| -> Result<i64, SiweError> {
+        match bytes.get(from..to) {
+            Some(r) if r.iter().all(u8::is_ascii_digit) => {
+                Ok(r.iter().fold(0, |acc, i| acc * 10 + i64::from(i - b'0')))
+            }
+            _ => Err(invalid()),
+        }
+    };
+
+    let separators = [(4, b'-'), (7, b'-'), (13, b':'), (16, b':')];
+
+    if separators.iter().any(|(i, r)| bytes.get(*i) != Some(r))
+        || !matches!(bytes.get(10), Some(b'T' | b't'))
+    {
+        return Err(invalid().into());
+    }
+
+    let (year, month, day) = (number(0, 4)?, number(5, 7)?, number(8, 10)?);
+    let (hour, minute, second) = (number(11, 13)?, number(14, 16)?, number(17, 19)?);
+
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let month_days = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+
+    if !(1..=12).contains(&month)
+        || !(1..=month_days).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return Err(invalid().into());
+    }
+
+    let mut rest = &value[19..];
+
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let digits = fraction.bytes().take_while(u8::is_ascii_digit).count();
+
+        if digits == 0 {
+            return Err(invalid().into());
+        }
+
+        rest = &fraction[digits..];
+    }
+
+    let offset = match rest {
+        "Z" | "z" => 0,
+        r if r.len() == 6 && matches!(r.as_bytes()[0], b'+' | b'-') && r.as_bytes()[3] == b':' => {
+            let part = |from: usize| match r.as_bytes().get(from..from + 2) {
+                Some(r) if r.iter().all(u8::is_ascii_digit) => {
+                    Ok(i64::from(r[0] - b'0') * 10 + i64::from(r[1] - b'0'))
+                }
+                _ => Err(invalid()),
+            };
+
+            let (hours, minutes) = (part(1)?, part(4)?);
+
+            if hours > 23 || minutes > 59 {
+                return Err(invalid().into());
+            }
+
+            let offset = hours * 3600 + minutes * 60;
+
+            if r.starts_with('-') { -offset } else { offset }
+        }
+        _ => return Err(invalid().into()),
+    };
+
+    Ok(days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second - offset)
+}
+
+/*
+    Unix milliseconds to `YYYY-MM-DDTHH:MM:SS.sssZ`, the JS `toISOString` form.
+*/
+pub fn format_timestamp(millis: u64) -> String {
+    let seconds = (millis / 1000) as i64;
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86400));
+    let time = seconds.rem_euclid(86400);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60,
+        millis % 1000
+    )
+}
+
+/*
+    Only `parse_message`, `validate` and `verify_offline` are safe in a server function:
+    `verify` reaches contract wallets through the provider, which for the default
+    `EtherAdapter` is the browser wallet bridge and does not exist on the server.
+*/
+pub struct SiweAdapter<P = EtherAdapter> {
+    signature: EtherSignatureAdapter<P>,
+}
+
+impl Default for SiweAdapter<EtherAdapter> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SiweAdapter<EtherAdapter> {
+    pub fn new() -> Self {
+        Self::with_provider(EtherAdapter::new())
+    }
+}
+
+impl<P> SiweAdapter<P> {
+    /*
+        Provider of the message chain, only queried for EIP-1271 and ERC-6492 wallets.
+    */
+    pub fn with_provider(provider: P) -> Self {
+        Self {
+            signature: EtherSignatureAdapter::with_provider(provider),
+        }
+    }
+
+    fn check_message(&self, message: &SiweMessage) -> Result<(), Error> {
+        let invalid = |field: &str, reason: &str| SiweError::InvalidField {
+            field: field.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let is_uri = |value: &str| {
+            value.split_once(':').is_some_and(|(scheme, _)| {
+                scheme.starts_with(|r: char| r.is_ascii_alphabetic())
+                    && scheme
+                        .chars()
+                        .all(|r| r.is_ascii_alphanumeric() || matches!(r, '+' | '-' | '.'))
+            }) && !value.contains(char::is_whitespace)
+        };
+
+        if let Some(scheme) = &message.scheme
+            && !is_uri(&format!("{}:", scheme))
+        {
+            return Err(invalid("scheme", "expected an URI scheme").into());
+        }
+
+        if message.domain.is_empty() || message.domain.contains(char::is_whitespace) {
+            return Err(invalid("domain", "expected an RFC 3986 authority").into());
+        }
+
+        if let Some(statement) = &message.statement
+            && statement.contains('\n')
+        {
+            return Err(invalid("statement", "must not contain new lines").into());
+        }
+
+        if !is_uri(&message.uri) {
+            return Err(invalid("uri", "expected an RFC 3986 URI").into());
+        }
+
+        if message.version != "1" {
+            return Err(invalid("version", "only version 1 is supported").into());
+        }
+
+        if message.nonce.len() < 8 || !message.nonce.chars().all(|r| r.is_ascii_alphanumeric()) {
+            return Err(invalid("nonce", "expected at least 8 alphanumeric characters").into());
+        }
+
+        for timestamp in [
+            Some(&message.issued_at),
+            message.expiration_time.as_ref(),
+            message.not_before.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            parse_timestamp(timestamp)?;
+        }
+
+        if let Some(request_id) = &message.request_id
+            && request_id.contains('\n')
+        {
+            return Err(invalid("request id", "must not contain new lines").into());
+        }
+
+        if message.resources.iter().any(|r| !is_uri(r)) {
+            return Err(invalid("resources", "expected RFC 3986 URIs").into());
+        }
+
+        Ok(())
+    }
+}
+
+impl<P> SiwePort for SiweAdapter<P>
+where
+    P: EtherCallPort<Output = String, Error = Error>
+        + EtherChainIdPort<Output = String, Error = Error>
+        + EtherGetCodePort<Output = String, Error = Error>,
+{
+    type Error = Error;
+
+    fn build_message(&self, message: &SiweMessage) -> Result<String, Self::Error> {
+        self.check_message(message)?;
+
+        let mut lines = Vec::new();
+
+        let origin = match &message.scheme {
+            Some(r) => format!("{}://{}", r, message.domain),
+            None => message.domain.clone(),
+        };
+
+        lines.push(format!("{}{}", origin, SIWE_HEADER));
+        lines.push(message.address.to_checksum(None));
+        lines.push(String::new());
+
+        if let Some(statement) = &message.statement {
+            lines.push(statement.clone());
+        }
+
+        lines.push(String::new());
+        lines.push(format!("URI: {}", message.uri));
+        lines.push(format!("Version: {}", message.version));
+        lines.push(format!("Chain ID: {}", message.chain_id));
+        lines.push(format!("Nonce: {}", message.nonce));
+        lines.push(format!("Issued At: {}", message.issued_at));
+
+        if let Some(r) = &message.expiration_time {
+            lines.push(format!("Expiration Time: {}", r));
+        }
+
+        if let Some(r) = &message.not_before {
+            lines.push(format!("Not Before: {}", r));
+        }
+
+        if let Some(r) = &message.request_id {
+            lines.push(format!("Request ID: {}", r));
+        }
+
+        if !message.resources.is_empty() {
+            lines.push(String::from("Resources:"));
+            lines.extend(message.resources.iter().map(|r| format!("- {}", r)));
+        }
+
+        Ok(lines.join("\n"))
+    }
+
+    /*
+        Strict EIP-4361 parsing: fields in spec order, EIP-55 address, no trailing lines.
+    */
+    fn parse_message(&self, message: &str) -> Result<SiweMessage, Self::Error> {
+        let invalid = |reason: &str| SiweError::InvalidMessage(reason.to_string());
+
+        let mut lines = message.split('\n').peekable();
+
+        let origin = lines
+            .next()
+            .and_then(|r| r.strip_suffix(SIWE_HEADER))
+            .ok_or_else(|| invalid("missing header"))?;
+
+        let (scheme, domain) = match origin.split_once("://") {
+            Some((scheme, domain)) => (Some(scheme.to_string()), domain.to_string()),
+            None => (None, origin.to_string()),
+        };
+
+        let address = lines.next().ok_or_else(|| invalid("missing address"))?;
+        let parsed = address
+            .parse::<Address>()
+            .map_err(|_| SiweError::InvalidField {
+                field: String::from("address"),
+                reason: format!("`{}` is not an address", address),
+            })?;
+
+        if parsed.to_checksum(None) != address {
+            return Err(SiweError::InvalidField {
+                field: String::from("address"),
+                reason: format!("`{}` is not EIP-55 checksummed", address),
+            }
+            .into());
+        }
+
+        if lines.next() != Some("") {
+            return Err(invalid("expected an empty line after the address").into());
+        }
+
+        let statement = match lines.next() {
+            Some("") => None,
+            Some(r) if lines.next() == Some("") => Some(r.to_string()),
+            _ => return Err(invalid("expected an empty line after the statement").into()),
+        };
+
+        let mut required = |tag: &str| {
+            lines
+                .next()
+                .and_then(|r| r.strip_prefix(tag))
+                .map(str::to_string)
+                .ok_or_else(|| invalid(&format!("missing `{}`", tag.trim_end())))
+        };
+
+        let uri = required("URI: ")?;
+        let version = required("Version: ")?;
+        let chain_id = required("Chain ID: ")?;
+        let nonce = required("Nonce: ")?;
+        let issued_at = required("Issued At: ")?;
+
+        let chain_id = match chain_id.parse::<u64>() {
+            Ok(r) if !chain_id.starts_with('+') => r,
+            _ => {
+                return Err(SiweError::InvalidField {
+                    field: String::from("chain id"),
+                    reason: format!("`{}` is not a number", chain_id),
+                }
+                .into());
+            }
+        };
+
+        let mut optional = |tag: &str| {
+            let value = lines.peek().and_then(|r| r.strip_prefix(tag))?;
+            let value = value.to_string();
+
+            lines.next();
+
+            Some(value)
+        };
+
+        let expiration_time = optional("Expiration Time: ");
+        let not_before = optional("Not Before: ");
+        let request_id = optional("Request ID: ");
+
+        let mut resources = Vec::new();
+
+        if lines.peek() == Some(&"Resources:") {
+            lines.next();
+
+            while let Some(r) = lines.peek().and_then(|r| r.strip_prefix("- ")) {
+                resources.push(r.to_string());
+                lines.next();
+            }
+        }
+
+        if lines.next().is_some() {
+            return Err(invalid("unexpected trailing lines").into());
+        }
+
+        let message = SiweMessage {
+            scheme,
+            domain,
+            address: parsed,
+            statement,
+            uri,
+            version,
+            chain_id,
+            nonce,
+            issued_at,
+            expiration_time,
+            not_before,
+            request_id,
+            resources,
+        };
+
+        self.check_message(&message)?;
+
+        Ok(message)
+    }
+
+    fn validate(
+        &self,
+        message: &SiweMessage,
+        options: &SiweVerifyOptions,
+    ) -> Result<(), Self::Error> {
+        if let Some(domain) = &options.domain
+            && *domain != message.domain
+        {
+            return Err(SiweError::DomainMismatch {
+                expected: domain.clone(),
+                found: message.domain.clone(),
+            }
+            .into());
+        }
+
+        if let Some(nonce) = &options.nonce
+            && *nonce != message.nonce
+        {
+            return Err(SiweError::NonceMismatch {
+                expected: nonce.clone(),
+                found: message.nonce.clone(),
+            }
+            .into());
+        }
+
+        if let Some(chain_id) = options.chain_id
+            && chain_id != message.chain_id
+        {
+            return Err(SiweError::ChainIdMismatch {
+                expected: chain_id,
+                found: message.chain_id,
+            }
+            .into());
+        }
+
+        let time = options.time as i64;
+
+        if let Some(r) = &message.expiration_time
+            && parse_timestamp(r)? <= time
+        {
+            return Err(SiweError::Expired(r.clone()).into());
+        }
+
+        if let Some(r) = &message.not_before
+            && parse_timestamp(r)? > time
+        {
+            return Err(SiweError::NotYetValid(r.clone()).into());
+        }
+
+        Ok(())
+    }
+
+    /*
+        EOA signatures only, no RPC involved, so it can run inside a server function.
+        Contract wallets (EIP-1271, ERC-6492) are rejected, verify those with `verify`.
+    */
+    fn verify_offline(
+        &self,
+        message: &str,
+        signature: &str,
+        options: &SiweVerifyOptions,
+    ) -> Result<SiweMessage, Self::Error> {
+        let parsed = self.parse_message(message)?;
+
+        self.validate(&parsed, options)?;
+
+        match self
+            .signature
+            .recover_message(message.as_bytes(), signature)
+        {
+            Ok(r) if r == parsed.address => Ok(parsed),
+            _ => Err(SiweError::InvalidSignature(parsed.address).into()),
+        }
+    }
+
+    /*
+        `ecrecover` first, EIP-1271 and ERC-6492 over the provider for contract wallets.
+        Client side only with the default `EtherAdapter` provider.
+    */
+    async fn verify(
+        &self,
+        message: &str,
+        signature: &str,
+        options: &SiweVerifyOptions,
+    ) -> Result<SiweMessage, Self::Error> {
+        let parsed = self.parse_message(message)?;
+
+        self.validate(&parsed, options)?;
+
+        if self
+            .signature
+            .recover_message(message.as_bytes(), signature)
+            .is_ok_and(|r| r == parsed.address)
+        {
+            return Ok(parsed);
+        }
+
+        match self
+            .signature
+            .verify_message(&parsed.address, message.as_bytes(), signature)
+            .await?
+        {
+            true => Ok(parsed),
+            false => Err(SiweError::InvalidSignature(parsed.address).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    // EIP-4361 example message;
+    const SPEC_MESSAGE: &str = "example.com wants you to sign in with your Ethereum account:
+0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2
+
+I accept the ExampleOrg Terms of Service: https://example.com/tos
+
+URI: https://example.com/login
+Version: 1
+Chain ID: 1
+Nonce: 32891756
+Issued At: 2021-09-30T16:25:24Z
+Resources:
+- ipfs://bafybeiemxf5abjwjbikoz4mc3a3dla6ual3jsgpdr4cjr3oz3evfyavhwq/
+- https://example.com/my-web2-claim.json";
+
+    // Scheme without a statement, `address LF LF LF "URI: "` per the ABNF;
+    const SCHEME_MESSAGE: &str =
+        "https://example.com wants you to sign in with your Ethereum account:
+0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2
+
+
+URI: https://example.com/login
+Version: 1
+Chain ID: 1
+Nonce: 32891756
+Issued At: 2021-09-30T16:25:24Z";
+
+    // Signed with the web3.js example key 0x4c0883a6...3f362318;
+    const SIGNED_MESSAGE: &str = "example.com wants you to sign in with your Ethereum account:
+0x2c7536E3605D9C16a7a3D7b1898e529396a65c23
+
+I accept the ExampleOrg Terms of Service: https://example.com/tos
+
+URI: https://example.com/login
+Version: 1
+Chain ID: 1
+Nonce: 32891756
+Issued At: 2021-09-30T16:25:24Z
+Expiration Time: 2021-10-01T16:25:24Z
+Not Before: 2021-09-30T16:25:24Z
+Request ID: some-id
+Resources:
+- ipfs://bafybeiemxf5abjwjbikoz4mc3a3dla6ual3jsgpdr4cjr3oz3evfyavhwq/
+- https://example.com/my-web2-claim.json";
+    const SIGNATURE: &str = "0x4b355b4bcce06816a786737b56572b8d508d27578474e0eaeedbd2aa85326e5e0ad4e4d9bd2c4da86a32ffc83b01d42d537071776810252aab882b00ac0bc9d21c";
+
+    const ISSUED_AT: u64 = 1633019124;
+    const EXPIRES_AT: u64 = 1633105524;
+
+    /*
+        Fails every request, offline checks must not reach the provider.
+    */
+    struct NoProvider;
+
+    impl EtherCallPort for NoProvider {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_call(
+            &self,
+            _address: Address,
+            _calldata: &str,
+            _block: Option<String>,
+        ) -> Result<Self::Output, Self::Error> {
+            Err("[eth_call]: no provider".into())
+        }
+    }
+
+    impl EtherChainIdPort for NoProvider {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_chain_id(&self) -> Result<Self::Output, Self::Error> {
+            Err("[eth_chainId]: no provider".into())
+        }
+    }
+
+    impl EtherGetCodePort for NoProvider {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_get_code(
+            &self,
+            _address: Address,
+            _block: Option<String>,
+        ) -> Result<Self::Output, Self::Error> {
+            Err("[eth_getCode]: no provider".into())
+        }
+    }
+
+    fn adapter() -> SiweAdapter<NoProvider> {
+        SiweAdapter::with_provider(NoProvider)
+    }
+
+    fn siwe_error(result: Result<impl std::fmt::Debug, Error>) -> SiweError {
+        result
+            .unwrap_err()
+            .downcast_ref::<SiweError>()
+            .cloned()
+            .expect("siwe error")
+    }
+
+    fn invalid_field(result: Result<impl std::fmt::Debug, Error>) -> String {
+        match siwe_error(result) {
+            SiweError::InvalidField { field, .. } => field,
+            r => panic!("unexpected {:?}", r),
+        }
+    }
+
+    #[test]
+    fn parses_spec_examples() {
+        let message = adapter().parse_message(SPEC_MESSAGE).unwrap();
+
+        assert_eq!(message.scheme, None);
+        assert_eq!(message.domain, "example.com");
+        assert_eq!(
+            message.address,
+            address!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")
+        );
+        assert_eq!(
+            message.statement.as_deref(),
+            Some("I accept the ExampleOrg Terms of Service: https://example.com/tos")
+        );
+        assert_eq!(message.chain_id, 1);
+        assert_eq!(message.nonce, "32891756");
+        assert_eq!(message.resources.len(), 2);
+        assert_eq!(adapter().build_message(&message).unwrap(), SPEC_MESSAGE);
+
+        let message = adapter().parse_message(SCHEME_MESSAGE).unwrap();
+
+        assert_eq!(message.scheme.as_deref(), Some("https"));
+        assert_eq!(message.statement, None);
+        assert_eq!(adapter().build_message(&message).unwrap(), SCHEME_MESSAGE);
+    }
+
+    #[test]
+    fn builds_with_optional_fields() {
+        let message = SiweMessage::new(
+            "example.com",
+            &address!("0x2c7536E3605D9C16a7a3D7b1898e529396a65c23"),
+            "https://example.com/login",
+            1,
+        )
+        .statement("I accept the ExampleOrg Terms of Service: https://example.com/tos")
+        .nonce("32891756")
+        .issued_at("2021-09-30T16:25:24Z")
+        .expiration_time("2021-10-01T16:25:24Z")
+        .not_before("2021-09-30T16:25:24Z")
+        .request_id("some-id")
+        .resource("ipfs://bafybeiemxf5abjwjbikoz4mc3a3dla6ual3jsgpdr4cjr3oz3evfyavhwq/")
+        .resource("https://example.com/my-web2-claim.json");
+
+        assert_eq!(adapter().build_message(&message).unwrap(), SIGNED_MESSAGE);
+        assert_eq!(adapter().parse_message(SIGNED_MESSAGE).unwrap(), message);
+    }
+
+    #[test]
+    fn rejects_malformed_messages() {
+        let lowercase = SPEC_MESSAGE.replace(
+            "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2",
+            "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+        );
+
+        assert_eq!(
+            invalid_field(adapter().parse_message(&lowercase)),
+            "address"
+        );
+        assert_eq!(
+            invalid_field(adapter().parse_message(&SPEC_MESSAGE.replace("32891756", "3289175"))),
+            "nonce"
+        );
+        assert_eq!(
+            invalid_field(
+                adapter().parse_message(&SPEC_MESSAGE.replace("Chain ID: 1", "Chain ID: +1"))
+            ),
+            "chain id"
+        );
+
+        for message in [
+            format!("{}\n", SPEC_MESSAGE),
+            format!("{}\n- https://example.com\nextra", SPEC_MESSAGE),
+            SPEC_MESSAGE.replace("Version: 1\nChain ID: 1", "Chain ID: 1\nVersion: 1"),
+            SPEC_MESSAGE.replace(" wants you", " wishes you"),
+        ] {
+            assert!(matches!(
+                siwe_error(adapter().parse_message(&message)),
+                SiweError::InvalidMessage(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_bad_timestamps() {
+        for issued_at in [
+            "2021-09-30T16:25:24",
+            "2021-09-30T16:25:24+24:00",
+            "2021-09-30T16:25:24+01:60",
+            "2021-09-30T16:25:24+0100",
+            "2021-09-30T16:25:24.Z",
+            "2021-09-30 16:25:24Z",
+            "2021-02-29T16:25:24Z",
+            "2021-13-01T16:25:24Z",
+        ] {
+            let message = SPEC_MESSAGE.replace("2021-09-30T16:25:24Z", issued_at);
+
+            assert_eq!(
+                invalid_field(adapter().parse_message(&message)),
+                "timestamp",
+                "{}",
+                issued_at
+            );
+        }
+    }
+
+    #[test]
+    fn parses_and_formats_timestamps() {
+        assert_eq!(parse_timestamp("1970-01-01T00:00:00Z").unwrap(), 0);
+        assert_eq!(
+            parse_timestamp("2021-09-30T16:25:24Z").unwrap(),
+            ISSUED_AT as i64
+        );
+        assert_eq!(
+            parse_timestamp("2021-09-30T18:25:24.999+02:00").unwrap(),
+            ISSUED_AT as i64
+        );
+        assert_eq!(
+            parse_timestamp("2021-09-30t14:25:24-02:00").unwrap(),
+            ISSUED_AT as i64
+        );
+        assert_eq!(parse_timestamp("2024-02-29T00:00:00Z").unwrap(), 1709164800);
+        assert_eq!(parse_timestamp("1969-12-31T23:59:59Z").unwrap(), -1);
+
+        assert_eq!(format_timestamp(0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(
+            format_timestamp(ISSUED_AT * 1000 + 123),
+            "2021-09-30T16:25:24.123Z"
+        );
+        assert_eq!(
+            parse_timestamp(&format_timestamp(1709164800 * 1000)).unwrap(),
+            1709164800
+        );
+    }
+
+    #[test]
+    fn converts_civil_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(civil_from_days(11016), (2000, 2, 29));
+
+        for days in (-800_000..800_000).step_by(97) {
+            let (year, month, day) = civil_from_days(days);
+
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn verifies_offline() {
+        let options = SiweVerifyOptions::new(ISSUED_AT + 60)
+            .domain("example.com")
+            .nonce("32891756");
+
+        let message = adapter()
+            .verify_offline(SIGNED_MESSAGE, SIGNATURE, &options)
+            .unwrap();
+
+        assert_eq!(
+            message.address,
+            address!("0x2c7536E3605D9C16a7a3D7b1898e529396a65c23")
+        );
+
+        let tampered = SIGNED_MESSAGE.replace("some-id", "other-id");
+
+        assert!(matches!(
+            siwe_error(adapter().verify_offline(&tampered, SIGNATURE, &options)),
+            SiweError::InvalidSignature(_)
+        ));
+    }
+
+    #[test]
+    fn checks_time_domain_nonce_and_chain_id() {
+        let message = adapter().parse_message(SIGNED_MESSAGE).unwrap();
+        let validate = |options: SiweVerifyOptions| adapter().validate(&message, &options);
+
+        assert!(validate(SiweVerifyOptions::new(ISSUED_AT)).is_ok());
+        assert!(validate(SiweVerifyOptions::new(EXPIRES_AT - 1)).is_ok());
+        assert!(matches!(
+            siwe_error(validate(SiweVerifyOptions::new(EXPIRES_AT))),
+            SiweError::Expired(_)
+        ));
+        assert!(matches!(
+            siwe_error(validate(SiweVerifyOptions::new(ISSUED_AT - 1))),
+            SiweError::NotYetValid(_)
+        ));
+        assert!(matches!(
+            siwe_error(validate(
+                SiweVerifyOptions::new(ISSUED_AT).domain("evil.com")
+            )),
+            SiweError::DomainMismatch { .. }
+        ));
+        assert!(matches!(
+            siwe_error(validate(
+                SiweVerifyOptions::new(ISSUED_AT).nonce("00000000")
+            )),
+            SiweError::NonceMismatch { .. }
+        ));
+        assert!(validate(SiweVerifyOptions::new(ISSUED_AT).chain_id(1)).is_ok());
+        assert_eq!(
+            siwe_error(validate(SiweVerifyOptions::new(ISSUED_AT).chain_id(10))),
+            SiweError::ChainIdMismatch {
+                expected: 10,
+                found: 1,
+            }
+        );
+    }
+}
@@ -0,0 +1,743 @@
+use alloy_primitives::{Address, B256, U256, aliases::U192, hex};
+use gloo_timers::future::sleep;
+use serde::de::DeserializeOwned;
+use std::time::Duration;
+
+use crate::{
+    abi::entry_point_abi::EntryPoint,
+    adapters::{
+        contract_adapter::ContractAdapter,
+        ether_adapter::{EtherAdapter, EtherBlock},
+        ether_chain_adapter::ChainId,
+    },
+    constants::entry_point_constants::ENTRY_POINT_V07_ADDRESS,
+    errors::{error::Error, ether_error::EtherError},
+    ports::{
+        bundler_port::BundlerPort,
+        contract_port::ContractPort,
+        ether::{
+            ether_call_port::EtherCallPort, ether_chain_id_port::EtherChainIdPort,
+            ether_gas_price_port::EtherGasPricePort,
+            ether_get_block_by_number_port::EtherGetBlockByNumberPort,
+            ether_max_priority_fee_per_gas_port::EtherMaxPriorityFeePerGasPort,
+            ether_send_transaction_port::EtherSendTransactionPort,
+            personal_sign_port::PersonalSignPort,
+        },
+        http_post_port::HttpPostPort,
+    },
+    types::{
+        personal_sign_types::{EtherPersonalSignMessage, EtherPersonalSignature},
+        user_operation_types::{EtherUserOperationGas, EtherUserOperationReceipt, UserOperation},
+    },
+};
+
+// Receipt polls of `wait_user_operation_receipt` before giving up;
+const MAX_RECEIPT_POLLS: usize = 300;
+
+/*
+    ERC-4337 bundler JSON-RPC client, requests go over `http_post` of the provider.
+*/
+pub struct BundlerAdapter<P = EtherAdapter> {
+    provider: P,
+    url: String,
+    entry_point: String,
+    poll_interval: Duration,
+}
+
+impl BundlerAdapter<EtherAdapter> {
+    pub fn new(url: &str) -> Self {
+        Self::with_provider(url, EtherAdapter::new())
+    }
+}
+
+impl<P> BundlerAdapter<P> {
+    pub fn with_provider(url: &str, provider: P) -> Self {
+        Self {
+            provider,
+            url: url.to_string(),
+            entry_point: String::from(ENTRY_POINT_V07_ADDRESS),
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+
+    pub fn entry_point(mut self, address: &str) -> Self {
+        self.entry_point = address.to_string();
+        self
+    }
+
+    /*
+        Receipt polling interval of `wait_user_operation_receipt`.
+    */
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn entry_point_address(&self) -> Result<Address, Error> {
+        self.entry_point
+            .parse::<Address>()
+            .map_err(|_| format!("[bundler]: invalid entry point {}", self.entry_point).into())
+    }
+}
+
+impl<P> BundlerAdapter<P>
+where
+    P: HttpPostPort<Output = String, Error = Error>,
+{
+    /*
+        JSON-RPC errors of the bundler come back as `EtherError` with the bundler code,
+        e.g. `-32500` rejected by the account or `-32602` invalid fields.
+    */
+    async fn request<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<T, Error> {
+        let body = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let response = self
+            .provider
+            .http_post(&self.url, &body.to_string())
+            .await?;
+        let mut response = serde_json::from_str::<serde_json::Value>(&response)?;
+
+        if let Some(error) = response.get("error").filter(|r| !r.is_null()) {
+            return Err(EtherError {
+                method: method.to_string(),
+                code: error["code"].as_i64(),
+                message: error["message"].as_str().unwrap_or_default().to_string(),
+                data: error.get("data").cloned(),
+                ..Default::default()
+            }
+            .into());
+        }
+
+        Ok(serde_json::from_value(response["result"].take())?)
+    }
+}
+
+impl<P> BundlerPort for BundlerAdapter<P>
+where
+    P: HttpPostPort<Output = String, Error = Error>,
+{
+    type Error = Error;
+
+    async fn supported_entry_points(&self) -> Result<Vec<String>, Self::Error> {
+        self.request("eth_supportedEntryPoints", serde_json::json!([]))
+            .await
+    }
+
+    async fn estimate_user_operation_gas(
+        &self,
+        user_operation: &UserOperation,
+    ) -> Result<EtherUserOperationGas, Self::Error> {
+        if user_operation.signature.is_empty() {
+            return Err("[bundler]: a dummy signature is required for the estimate".into());
+        }
+
+        self.request(
+            "eth_estimateUserOperationGas",
+            serde_json::json!([user_operation.to_rpc(), self.entry_point]),
+        )
+        .await
+    }
+
+    async fn send_user_operation(
+        &self,
+        user_operation: &UserOperation,
+    ) -> Result<String, Self::Error> {
+        if user_operation.signature.is_empty() {
+            return Err("[bundler]: the user operation is not signed".into());
+        }
+
+        self.request(
+            "eth_sendUserOperation",
+            serde_json::json!([user_operation.to_rpc(), self.entry_point]),
+        )
+        .await
+    }
+
+    async fn get_user_operation_receipt(
+        &self,
+        hash: &str,
+    ) -> Result<Option<EtherUserOperationReceipt>, Self::Error> {
+        self.request("eth_getUserOperationReceipt", serde_json::json!([hash]))
+            .await
+    }
+
+    async fn wait_user_operation_receipt(
+        &self,
+        hash: &str,
+    ) -> Result<EtherUserOperationReceipt, Self::Error> {
+        for _ in 0..MAX_RECEIPT_POLLS {
+            if let Some(r) = self.get_user_operation_receipt(hash).await? {
+                return Ok(r);
+            }
+
+            sleep(self.poll_interval).await;
+        }
+
+        Err(format!("[bundler]: no receipt for {}", hash).into())
+    }
+}
+
+impl<P> BundlerAdapter<P>
+where
+    P: HttpPostPort<Output = String, Error = Error>
+        + EtherCallPort<Output = String, Error = Error>
+        + EtherSendTransactionPort<Output = String, Error = Error>
+        + EtherChainIdPort<Output = String, Error = Error>
+        + EtherGasPricePort<Output = String, Error = Error>
+        + EtherMaxPriorityFeePerGasPort<Output = String, Error = Error>
+        + EtherGetBlockByNumberPort<Output = Option<EtherBlock>, Error = Error>
+        + PersonalSignPort<Output = EtherPersonalSignature, Error = Error>
+        + Clone,
+{
+    /*
+        Next nonce of `sender` for the 192 bits nonce `key`, read from the EntryPoint.
+    */
+    pub async fn get_nonce(&self, sender: Address, key: U192) -> Result<U256, Error> {
+        ContractAdapter::<EntryPoint::EntryPointCalls, P>::with_provider(
+            self.entry_point_address()?,
+            self.provider.clone(),
+        )
+        .read(EntryPoint::getNonceCall { sender, key }, None)
+        .await
+    }
+
+    pub async fn user_operation_hash(&self, user_operation: &UserOperation) -> Result<B256, Error> {
+        let chain_id = self.provider.eth_chain_id().await?.parse::<ChainId>()?;
+
+        Ok(user_operation.hash(self.entry_point_address()?, chain_id))
+    }
+
+    /*
+        Fill unset (zero) fields: the nonce sequence from the EntryPoint, the tip from
+        `eth_maxPriorityFeePerGas` with twice the latest base fee on top for the max fee,
+        and gas limits from the bundler estimate.
+        The estimate runs with the signature set, accounts need a dummy one of the right shape.
+    */
+    pub async fn prepare_user_operation(
+        &self,
+        user_operation: &UserOperation,
+    ) -> Result<UserOperation, Error> {
+        let mut user_operation = user_operation.clone();
+
+        if user_operation.nonce.as_limbs()[0] == 0 {
+            let key = U192::from_limbs_slice(&user_operation.nonce.as_limbs()[1..]);
+
+            user_operation.nonce = self.get_nonce(user_operation.sender, key).await?;
+        }
+
+        if user_operation.max_fee_per_gas == 0 {
+            let (max_fee_per_gas, max_priority_fee_per_gas) = self.fees().await?;
+
+            user_operation = user_operation.fees(max_fee_per_gas, max_priority_fee_per_gas);
+        }
+
+        if user_operation.call_gas_limit == 0
+            || user_operation.verification_gas_limit == 0
+            || user_operation.pre_verification_gas == 0
+        {
+            let gas = self.estimate_user_operation_gas(&user_operation).await?;
+
+            user_operation = user_operation.gas(&gas);
+        }
+
+        Ok(user_operation)
+    }
+
+    /*
+        `(max_fee_per_gas, max_priority_fee_per_gas)`, chains without a base fee pay the
+        legacy gas price for both.
+    */
+    async fn fees(&self) -> Result<(u128, u128), Error> {
+        let base_fee = self
+            .provider
+            .eth_get_block_by_number("latest", false)
+            .await?
+            .and_then(|r| r.base_fee_per_gas);
+
+        let Some(base_fee) = base_fee else {
+            let gas_price = parse_fee(&self.provider.eth_gas_price().await?)?;
+
+            return Ok((gas_price, gas_price));
+        };
+
+        let base_fee = parse_fee(&base_fee)?;
+        let priority_fee = parse_fee(&self.provider.eth_max_priority_fee_per_gas().await?)?;
+
+        Ok((
+            base_fee.saturating_mul(2).saturating_add(priority_fee),
+            priority_fee,
+        ))
+    }
+
+    /*
+        EIP-191 signature of the `userOpHash` by the account owner, the scheme of
+        ECDSA owned accounts such as `SimpleAccount`.
+    */
+    pub async fn sign_user_operation(
+        &self,
+        user_operation: &UserOperation,
+        owner: &Address,
+    ) -> Result<UserOperation, Error> {
+        let hash = self.user_operation_hash(user_operation).await?;
+
+        let signature = self
+            .provider
+            .personal_sign(&EtherPersonalSignMessage::from(hash), owner)
+            .await?;
+
+        Ok(user_operation
+            .clone()
+            .signature(hex::decode(&signature.signature)?.into()))
+    }
+}
+
+// Fees come back in decimal from the adapter, block fields in `0x` hex;
+fn parse_fee(value: &str) -> Result<u128, Error> {
+    match value.strip_prefix("0x") {
+        Some(r) => u128::from_str_radix(r, 16),
+        None => value.parse::<u128>(),
+    }
+    .map_err(|_| format!("[bundler]: invalid fee {}", value).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::block_on;
+    use alloy_primitives::{Bytes, address, b256};
+    use alloy_sol_types::SolValue;
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    const SENDER: Address = address!("0x1111111111111111111111111111111111111111");
+    const FACTORY: Address = address!("0x2222222222222222222222222222222222222222");
+    const PAYMASTER: Address = address!("0x3333333333333333333333333333333333333333");
+    const OWNER: Address = address!("0x4444444444444444444444444444444444444444");
+
+    fn user_operation() -> UserOperation {
+        UserOperation::new(SENDER)
+            .nonce(U256::from_limbs([5, 1, 0, 0]))
+            .factory(FACTORY, Bytes::from(vec![0xde, 0xad, 0xbe, 0xef]))
+            .call_data(Bytes::from(vec![0xb6, 0x1d, 0x27, 0xf6]))
+            .gas(&EtherUserOperationGas {
+                pre_verification_gas: 50_000,
+                verification_gas_limit: 200_000,
+                call_gas_limit: 100_000,
+                paymaster_verification_gas_limit: 60_000,
+                paymaster_post_op_gas_limit: 30_000,
+            })
+            .fees(3_000_000_000, 1_000_000_000)
+            .paymaster(PAYMASTER, Bytes::from(vec![0x12, 0x34]))
+            .signature(Bytes::from(vec![0xff; 65]))
+    }
+
+    /*
+        Bundler answering each JSON-RPC method with a canned body, and a wallet with a
+        fixed nonce, base fee and tip.
+    */
+    #[derive(Clone, Default)]
+    struct StubBundler {
+        responses: HashMap<String, String>,
+        base_fee: Option<String>,
+        requests: Arc<Mutex<Vec<serde_json::Value>>>,
+        signed: Arc<Mutex<Vec<EtherPersonalSignMessage>>>,
+    }
+
+    impl StubBundler {
+        fn with(mut self, method: &str, response: &str) -> Self {
+            self.responses
+                .insert(method.to_string(), response.to_string());
+            self
+        }
+
+        fn requests(&self) -> Vec<serde_json::Value> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    impl HttpPostPort for StubBundler {
+        type Output = String;
+        type Error = Error;
+
+        async fn http_post(&self, _url: &str, body: &str) -> Result<Self::Output, Self::Error> {
+            let body = serde_json::from_str::<serde_json::Value>(body)?;
+            let method = body["method"].as_str().unwrap_or_default().to_string();
+
+            self.requests.lock().unwrap().push(body);
+
+            self.responses
+                .get(&method)
+                .cloned()
+                .ok_or_else(|| format!("[http_post]: {} unreachable", method).into())
+        }
+    }
+
+    impl EtherCallPort for StubBundler {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_call(
+            &self,
+            _address: Address,
+            _calldata: &str,
+            _block: Option<String>,
+        ) -> Result<Self::Output, Self::Error> {
+            Ok(hex::encode_prefixed(
+                U256::from_limbs([7, 1, 0, 0]).abi_encode(),
+            ))
+        }
+    }
+
+    impl EtherSendTransactionPort for StubBundler {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_send_transaction(
+            &self,
+            _from: Address,
+            _to: Address,
+            _value: Option<String>,
+            _calldata: Option<String>,
+            _gas: Option<String>,
+            _gas_price: Option<String>,
+        ) -> Result<Self::Output, Self::Error> {
+            Err("[eth_sendTransaction]: unexpected".into())
+        }
+    }
+
+    impl EtherChainIdPort for StubBundler {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_chain_id(&self) -> Result<Self::Output, Self::Error> {
+            Ok(String::from("11155111"))
+        }
+    }
+
+    impl EtherGasPricePort for StubBundler {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_gas_price(&self) -> Result<Self::Output, Self::Error> {
+            Ok(String::from("4000000000"))
+        }
+    }
+
+    impl EtherMaxPriorityFeePerGasPort for StubBundler {
+        type Output = String;
+        type Error = Error;
+
+        async fn eth_max_priority_fee_per_gas(&self) -> Result<Self::Output, Self::Error> {
+            Ok(String::from("1500000000"))
+        }
+    }
+
+    impl EtherGetBlockByNumberPort for StubBundler {
+        type Output = Option<EtherBlock>;
+        type Error = Error;
+
+        async fn eth_get_block_by_number(
+            &self,
+            _block: &str,
+            _full_transactions: bool,
+        ) -> Result<Self::Output, Self::Error> {
+            Ok(Some(EtherBlock {
+                base_fee_per_gas: self.base_fee.clone(),
+                ..Default::default()
+            }))
+        }
+    }
+
+    impl PersonalSignPort for StubBundler {
+        type Output = EtherPersonalSignature;
+        type Error = Error;
+
+        async fn personal_sign(
+            &self,
+            message: &EtherPersonalSignMessage,
+            _address: &Address,
+        ) -> Result<Self::Output, Self::Error> {
+            self.signed.lock().unwrap().push(message.clone());
+
+            Ok(EtherPersonalSignature {
+                signature: hex::encode_prefixed([0xaa; 65]),
+                ..Default::default()
+            })
+        }
+    }
+
+    fn bundler(provider: StubBundler) -> BundlerAdapter<StubBundler> {
+        BundlerAdapter::with_provider("https://bundler.test/", provider)
+    }
+
+    #[test]
+    fn hashes_v07_user_operation() {
+        let hash =
+            user_operation().hash(ENTRY_POINT_V07_ADDRESS.parse().unwrap(), ChainId(11155111));
+
+        assert_eq!(
+            hash,
+            b256!("0xbbe6608a5157dccc26ac9e311e0cbd8cdcb44f46b3daffd298ee308ce5139dd2")
+        );
+
+        // The signature is not part of the hash;
+        assert_eq!(
+            user_operation()
+                .signature(Bytes::new())
+                .hash(ENTRY_POINT_V07_ADDRESS.parse().unwrap(), ChainId(11155111)),
+            hash
+        );
+    }
+
+    #[test]
+    fn packs_gas_and_paymaster() {
+        let packed = user_operation().pack();
+
+        assert_eq!(
+            packed.accountGasLimits,
+            b256!("0x00000000000000000000000000030d40000000000000000000000000000186a0")
+        );
+        assert_eq!(
+            packed.gasFees,
+            b256!("0x0000000000000000000000003b9aca00000000000000000000000000b2d05e00")
+        );
+        assert_eq!(
+            hex::encode_prefixed(&packed.initCode),
+            "0x2222222222222222222222222222222222222222deadbeef"
+        );
+        assert_eq!(
+            hex::encode_prefixed(&packed.paymasterAndData),
+            "0x33333333333333333333333333333333333333330000000000000000000000000000ea60000000000000000000000000000075301234"
+        );
+
+        let packed = UserOperation::new(SENDER).pack();
+
+        assert!(packed.initCode.is_empty());
+        assert!(packed.paymasterAndData.is_empty());
+    }
+
+    #[test]
+    fn serializes_rpc_fields() {
+        assert_eq!(
+            user_operation().to_rpc(),
+            serde_json::json!({
+                "sender": SENDER.to_checksum(None),
+                "nonce": "0x10000000000000005",
+                "factory": FACTORY.to_checksum(None),
+                "factoryData": "0xdeadbeef",
+                "callData": "0xb61d27f6",
+                "callGasLimit": "0x186a0",
+                "verificationGasLimit": "0x30d40",
+                "preVerificationGas": "0xc350",
+                "maxFeePerGas": "0xb2d05e00",
+                "maxPriorityFeePerGas": "0x3b9aca00",
+                "paymaster": PAYMASTER.to_checksum(None),
+                "paymasterVerificationGasLimit": "0xea60",
+                "paymasterPostOpGasLimit": "0x7530",
+                "paymasterData": "0x1234",
+                "signature": hex::encode_prefixed([0xff; 65]),
+            })
+        );
+
+        let value = UserOperation::new(SENDER).to_rpc();
+
+        assert!(value.get("factory").is_none());
+        assert!(value.get("paymaster").is_none());
+    }
+
+    #[test]
+    fn deserializes_quantities() {
+        let gas = serde_json::from_str::<EtherUserOperationGas>(
+            r#"{"preVerificationGas":"0xc350","verificationGasLimit":200000,"callGasLimit":"100000","paymasterVerificationGasLimit":null}"#,
+        )
+        .unwrap();
+
+        assert_eq!(gas.pre_verification_gas, 50_000);
+        assert_eq!(gas.verification_gas_limit, 200_000);
+        assert_eq!(gas.call_gas_limit, 100_000);
+        assert_eq!(gas.paymaster_verification_gas_limit, 0);
+        assert_eq!(gas.paymaster_post_op_gas_limit, 0);
+
+        for invalid in [
+            r#"{"preVerificationGas":"0xzz","verificationGasLimit":1,"callGasLimit":1}"#,
+            r#"{"preVerificationGas":-1,"verificationGasLimit":1,"callGasLimit":1}"#,
+            r#"{"preVerificationGas":true,"verificationGasLimit":1,"callGasLimit":1}"#,
+        ] {
+            assert!(serde_json::from_str::<EtherUserOperationGas>(invalid).is_err());
+        }
+    }
+
+    #[test]
+    fn estimates_and_sends() {
+        let provider = StubBundler::default()
+            .with(
+                "eth_estimateUserOperationGas",
+                r#"{"jsonrpc":"2.0","id":1,"result":{"preVerificationGas":"0x1","verificationGasLimit":"0x2","callGasLimit":"0x3"}}"#,
+            )
+            .with(
+                "eth_sendUserOperation",
+                r#"{"jsonrpc":"2.0","id":1,"result":"0xabcd"}"#,
+            );
+        let bundler = bundler(provider);
+
+        let gas = block_on(bundler.estimate_user_operation_gas(&user_operation())).unwrap();
+
+        assert_eq!(
+            (
+                gas.pre_verification_gas,
+                gas.verification_gas_limit,
+                gas.call_gas_limit
+            ),
+            (1, 2, 3)
+        );
+        assert_eq!(
+            block_on(bundler.send_user_operation(&user_operation())).unwrap(),
+            "0xabcd"
+        );
+
+        let requests = bundler.provider.requests();
+
+        assert_eq!(requests[1]["method"], "eth_sendUserOperation");
+        assert_eq!(
+            requests[1]["params"],
+            serde_json::json!([user_operation().to_rpc(), ENTRY_POINT_V07_ADDRESS])
+        );
+    }
+
+    #[test]
+    fn rejects_empty_signature() {
+        let bundler = bundler(StubBundler::default());
+        let unsigned = user_operation().signature(Bytes::new());
+
+        let error = block_on(bundler.estimate_user_operation_gas(&unsigned)).unwrap_err();
+
+        assert!(error.to_string().contains("dummy signature"));
+        assert!(block_on(bundler.send_user_operation(&unsigned)).is_err());
+        assert!(bundler.provider.requests().is_empty());
+    }
+
+    #[test]
+    fn reads_receipt() {
+        let provider = StubBundler::default().with(
+            "eth_getUserOperationReceipt",
+            r#"{"jsonrpc":"2.0","id":1,"result":{"userOpHash":"0xabcd","success":false,"reason":"0x08c379a0"}}"#,
+        );
+        let adapter = bundler(provider);
+
+        let receipt = block_on(adapter.get_user_operation_receipt("0xabcd"))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(receipt.user_op_hash, "0xabcd");
+        assert!(!receipt.success);
+        assert_eq!(receipt.reason.as_deref(), Some("0x08c379a0"));
+
+        let provider = StubBundler::default().with(
+            "eth_getUserOperationReceipt",
+            r#"{"jsonrpc":"2.0","id":1,"result":null}"#,
+        );
+
+        assert!(
+            block_on(bundler(provider).get_user_operation_receipt("0xabcd"))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn maps_json_rpc_errors() {
+        let provider = StubBundler::default().with(
+            "eth_sendUserOperation",
+            r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32500,"message":"AA21 didn't pay prefund","data":"0x01"}}"#,
+        );
+
+        let error = block_on(bundler(provider).send_user_operation(&user_operation())).unwrap_err();
+        let error = EtherError::from_error(&error).unwrap();
+
+        assert_eq!(error.method, "eth_sendUserOperation");
+        assert_eq!(error.code, Some(-32500));
+        assert_eq!(error.message, "AA21 didn't pay prefund");
+        assert_eq!(error.data, Some(serde_json::json!("0x01")));
+    }
+
+    #[test]
+    fn prepares_nonce_fees_and_gas() {
+        let provider = StubBundler {
+            base_fee: Some(String::from("0x3b9aca00")),
+            ..Default::default()
+        }
+        .with(
+            "eth_estimateUserOperationGas",
+            r#"{"jsonrpc":"2.0","id":1,"result":{"preVerificationGas":"0x1","verificationGasLimit":"0x2","callGasLimit":"0x3"}}"#,
+        );
+        let bundler = bundler(provider);
+
+        let user_operation = UserOperation::new(SENDER)
+            .nonce(U256::from_limbs([0, 1, 0, 0]))
+            .signature(Bytes::from(vec![0xff; 65]));
+        let user_operation = block_on(bundler.prepare_user_operation(&user_operation)).unwrap();
+
+        assert_eq!(user_operation.nonce, U256::from_limbs([7, 1, 0, 0]));
+        assert_eq!(user_operation.max_priority_fee_per_gas, 1_500_000_000);
+        assert_eq!(user_operation.max_fee_per_gas, 3_500_000_000);
+        assert_eq!(
+            (
+                user_operation.pre_verification_gas,
+                user_operation.verification_gas_limit,
+                user_operation.call_gas_limit
+            ),
+            (1, 2, 3)
+        );
+    }
+
+    #[test]
+    fn falls_back_to_gas_price_without_base_fee() {
+        let bundler = bundler(StubBundler::default());
+
+        let user_operation =
+            UserOperation::new(SENDER)
+                .nonce(U256::from(1))
+                .gas(&EtherUserOperationGas {
+                    pre_verification_gas: 1,
+                    verification_gas_limit: 1,
+                    call_gas_limit: 1,
+                    ..Default::default()
+                });
+        let user_operation = block_on(bundler.prepare_user_operation(&user_operation)).unwrap();
+
+        assert_eq!(user_operation.max_fee_per_gas, 4_000_000_000);
+        assert_eq!(user_operation.max_priority_fee_per_gas, 4_000_000_000);
+        assert!(bundler.provider.requests().is_empty());
+    }
+
+    #[test]
+    fn signs_user_operation_hash() {
+        let bundler = bundler(StubBundler::default());
+
+        let signed = block_on(bundler.sign_user_operation(&user_operation(), &OWNER)).unwrap();
+
+        assert_eq!(signed.signature, Bytes::from(vec![0xaa; 65]));
+        assert_eq!(
+            *bundler.provider.signed.lock().unwrap(),
+            vec![EtherPersonalSignMessage::from(user_operation().hash(
+                ENTRY_POINT_V07_ADDRESS.parse().unwrap(),
+                ChainId(11155111)
+            ))]
+        );
+    }
+}
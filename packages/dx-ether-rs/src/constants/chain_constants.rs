@@ -0,0 +1,215 @@
+/*
+    Well-known chain seeded into the chain registry, Multicall3 and ENS addresses
+    come from their own constants.
+*/
+pub struct ChainConstant {
+    pub chain_id: u64,
+    pub name: &'static str,
+    // (name, symbol, decimals);
+    pub currency: (&'static str, &'static str, u8),
+    pub rpc_urls: &'static [&'static str],
+    pub explorer_url: Option<&'static str>,
+    pub testnet: bool,
+    pub wrapped_native: Option<&'static str>,
+}
+
+pub const CHAINS: &[ChainConstant] = &[
+    ChainConstant {
+        chain_id: 1,
+        name: "Ethereum",
+        currency: ("Ether", "ETH", 18),
+        rpc_urls: &[
+            "https://cloudflare-eth.com",
+            "https://ethereum-rpc.publicnode.com",
+        ],
+        explorer_url: Some("https://etherscan.io"),
+        testnet: false,
+        wrapped_native: Some("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"),
+    },
+    ChainConstant {
+        chain_id: 10,
+        name: "OP Mainnet",
+        currency: ("Ether", "ETH", 18),
+        rpc_urls: &["https://mainnet.optimism.io"],
+        explorer_url: Some("https://optimistic.etherscan.io"),
+        testnet: false,
+        wrapped_native: Some("0x4200000000000000000000000000000000000006"),
+    },
+    ChainConstant {
+        chain_id: 30,
+        name: "Rootstock",
+        currency: ("Rootstock Smart Bitcoin", "RBTC", 18),
+        rpc_urls: &["https://public-node.rsk.co"],
+        explorer_url: Some("https://explorer.rootstock.io"),
+        testnet: false,
+        wrapped_native: None,
+    },
+    ChainConstant {
+        chain_id: 31,
+        name: "Rootstock Testnet",
+        currency: ("Testnet Rootstock Smart Bitcoin", "tRBTC", 18),
+        rpc_urls: &["https://public-node.testnet.rsk.co"],
+        explorer_url: Some("https://explorer.testnet.rootstock.io"),
+        testnet: true,
+        wrapped_native: None,
+    },
+    ChainConstant {
+        chain_id: 56,
+        name: "BNB Smart Chain",
+        currency: ("BNB", "BNB", 18),
+        rpc_urls: &["https://bsc-dataseed.bnbchain.org"],
+        explorer_url: Some("https://bscscan.com"),
+        testnet: false,
+        wrapped_native: Some("0xbb4CdB9CBd36B01bD1cBaEBF2De08d9173bc095c"),
+    },
+    ChainConstant {
+        chain_id: 100,
+        name: "Gnosis",
+        currency: ("xDAI", "XDAI", 18),
+        rpc_urls: &["https://rpc.gnosischain.com"],
+        explorer_url: Some("https://gnosisscan.io"),
+        testnet: false,
+        wrapped_native: Some("0xe91D153E0b41518A2Ce8Dd3D7944Fa863463a97d"),
+    },
+    ChainConstant {
+        chain_id: 137,
+        name: "Polygon",
+        currency: ("POL", "POL", 18),
+        rpc_urls: &["https://polygon-rpc.com"],
+        explorer_url: Some("https://polygonscan.com"),
+        testnet: false,
+        wrapped_native: Some("0x0d500B1d8E8eF31E21C99d1Db9A6444d3ADf1270"),
+    },
+    ChainConstant {
+        chain_id: 300,
+        name: "ZKsync Sepolia",
+        currency: ("Sepolia Ether", "ETH", 18),
+        rpc_urls: &["https://sepolia.era.zksync.dev"],
+        explorer_url: Some("https://sepolia.explorer.zksync.io"),
+        testnet: true,
+        wrapped_native: None,
+    },
+    ChainConstant {
+        chain_id: 324,
+        name: "ZKsync Era",
+        currency: ("Ether", "ETH", 18),
+        rpc_urls: &["https://mainnet.era.zksync.io"],
+        explorer_url: Some("https://explorer.zksync.io"),
+        testnet: false,
+        wrapped_native: None,
+    },
+    ChainConstant {
+        chain_id: 8453,
+        name: "Base",
+        currency: ("Ether", "ETH", 18),
+        rpc_urls: &["https://mainnet.base.org"],
+        explorer_url: Some("https://basescan.org"),
+        testnet: false,
+        wrapped_native: Some("0x4200000000000000000000000000000000000006"),
+    },
+    ChainConstant {
+        chain_id: 17000,
+        name: "Holesky",
+        currency: ("Holesky Ether", "ETH", 18),
+        rpc_urls: &["https://ethereum-holesky-rpc.publicnode.com"],
+        explorer_url: Some("https://holesky.etherscan.io"),
+        testnet: true,
+        wrapped_native: None,
+    },
+    ChainConstant {
+        chain_id: 31337,
+        name: "Localhost",
+        currency: ("Ether", "ETH", 18),
+        rpc_urls: &["http://127.0.0.1:8545"],
+        explorer_url: None,
+        testnet: true,
+        wrapped_native: None,
+    },
+    ChainConstant {
+        chain_id: 42161,
+        name: "Arbitrum One",
+        currency: ("Ether", "ETH", 18),
+        rpc_urls: &["https://arb1.arbitrum.io/rpc"],
+        explorer_url: Some("https://arbiscan.io"),
+        testnet: false,
+        wrapped_native: Some("0x82aF49447D8a07e3bd95BD0d56f35241523fBab1"),
+    },
+    ChainConstant {
+        chain_id: 43114,
+        name: "Avalanche",
+        currency: ("Avalanche", "AVAX", 18),
+        rpc_urls: &["https://api.avax.network/ext/bc/C/rpc"],
+        explorer_url: Some("https://snowtrace.io"),
+        testnet: false,
+        wrapped_native: Some("0xB31f66AA3C1e785363F0875A1B74E27b85FD66c7"),
+    },
+    ChainConstant {
+        chain_id: 59144,
+        name: "Linea",
+        currency: ("Ether", "ETH", 18),
+        rpc_urls: &["https://rpc.linea.build"],
+        explorer_url: Some("https://lineascan.build"),
+        testnet: false,
+        wrapped_native: None,
+    },
+    ChainConstant {
+        chain_id: 80002,
+        name: "Polygon Amoy",
+        currency: ("POL", "POL", 18),
+        rpc_urls: &["https://rpc-amoy.polygon.technology"],
+        explorer_url: Some("https://amoy.polygonscan.com"),
+        testnet: true,
+        wrapped_native: None,
+    },
+    ChainConstant {
+        chain_id: 84532,
+        name: "Base Sepolia",
+        currency: ("Sepolia Ether", "ETH", 18),
+        rpc_urls: &["https://sepolia.base.org"],
+        explorer_url: Some("https://sepolia.basescan.org"),
+        testnet: true,
+        wrapped_native: Some("0x4200000000000000000000000000000000000006"),
+    },
+    ChainConstant {
+        chain_id: 421614,
+        name: "Arbitrum Sepolia",
+        currency: ("Sepolia Ether", "ETH", 18),
+        rpc_urls: &["https://sepolia-rollup.arbitrum.io/rpc"],
+        explorer_url: Some("https://sepolia.arbiscan.io"),
+        testnet: true,
+        wrapped_native: None,
+    },
+    ChainConstant {
+        chain_id: 534352,
+        name: "Scroll",
+        currency: ("Ether", "ETH", 18),
+        rpc_urls: &["https://rpc.scroll.io"],
+        explorer_url: Some("https://scrollscan.com"),
+        testnet: false,
+        wrapped_native: None,
+    },
+    ChainConstant {
+        chain_id: 11155111,
+        name: "Sepolia",
+        currency: ("Sepolia Ether", "ETH", 18),
+        rpc_urls: &[
+            "https://ethereum-sepolia-rpc.publicnode.com",
+            "https://rpc.sepolia.org",
+        ],
+        explorer_url: Some("https://sepolia.etherscan.io"),
+        testnet: true,
+        wrapped_native: Some("0x7b79995e5f793a07bc00c21412e50ecae098e7f9"),
+    },
+    ChainConstant {
+        chain_id: 11155420,
+        name: "OP Sepolia",
+        currency: ("Sepolia Ether", "ETH", 18),
+        rpc_urls: &["https://sepolia.optimism.io"],
+        explorer_url: Some("https://sepolia-optimism.etherscan.io"),
+        testnet: true,
+        wrapped_native: Some("0x4200000000000000000000000000000000000006"),
+    },
+];
+
+// Chains with EIP-1191 chain specific address checksums: Rootstock and Rootstock Testnet;
+pub const EIP1191_CHAIN_IDS: &[u64] = &[30, 31];
@@ -0,0 +1,9 @@
+// ENS registry with fallback, (chain id, address): mainnet, Sepolia and Holesky;
+pub const ENS_REGISTRY_ADDRESSES: &[(u64, &str)] = &[
+    (1, "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e"),
+    (17000, "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e"),
+    (11155111, "0x00000000000C2E074eC69A0dFb2997BA6C7d2e1e"),
+];
+
+// ENSIP-10 `resolve(bytes,bytes)`;
+pub const ENS_EXTENDED_RESOLVER_INTERFACE_ID: [u8; 4] = [0x90, 0x61, 0xb9, 0x23];
@@ -0,0 +1,2 @@
+// ERC-4337 EntryPoint v0.7, the same address on every supported chain;
+pub const ENTRY_POINT_V07_ADDRESS: &str = "0x0000000071727De22E5E9d8BAf0edAc6f37da032";
@@ -1 +1,6 @@
+pub mod chain_constants;
+pub mod ens_constants;
+pub mod entry_point_constants;
+pub mod multicall3_constants;
 pub mod scripts_constants;
+pub mod signature_constants;
@@ -0,0 +1,8 @@
+// Deterministic deployment, the same address on most EVM chains;
+pub const MULTICALL3_ADDRESS: &str = "0xcA11bde05977b3631167028862bE2a173976CA11";
+
+// Chains where Multicall3 lives elsewhere, (chain id, address);
+pub const MULTICALL3_CHAIN_ADDRESSES: &[(u64, &str)] = &[
+    (300, "0xF9cda624FBC7e059355ce98a31693d299FACd963"),
+    (324, "0xF9cda624FBC7e059355ce98a31693d299FACd963"),
+];
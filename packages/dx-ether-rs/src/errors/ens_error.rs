@@ -0,0 +1,20 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnsError {
+    InvalidName { name: String, reason: String },
+    UnsupportedChain(u64),
+}
+
+impl fmt::Display for EnsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName { name, reason } => {
+                write!(f, "[ens]: invalid name `{}`: {}", name, reason)
+            }
+            Self::UnsupportedChain(r) => write!(f, "[ens]: no registry for chain {}", r),
+        }
+    }
+}
+
+impl std::error::Error for EnsError {}
@@ -0,0 +1,18 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EtherAddressError {
+    InvalidAddress(String),
+    InvalidChecksum(String),
+}
+
+impl fmt::Display for EtherAddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidAddress(r) => write!(f, "[address]: invalid address `{}`", r),
+            Self::InvalidChecksum(r) => write!(f, "[address]: invalid checksum `{}`", r),
+        }
+    }
+}
+
+impl std::error::Error for EtherAddressError {}
@@ -0,0 +1,30 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EtherCallsError {
+    AtomicUnsupported,
+    PaymasterUnsupported,
+    // Transactions of the sequential fallback already sent when a later call failed;
+    Interrupted { sent: Vec<String>, reason: String },
+}
+
+impl fmt::Display for EtherCallsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AtomicUnsupported => {
+                write!(
+                    f,
+                    "[calls]: atomic execution required, not supported by the wallet"
+                )
+            }
+            Self::PaymasterUnsupported => {
+                write!(f, "[calls]: paymaster service not supported by the wallet")
+            }
+            Self::Interrupted { sent, reason } => {
+                write!(f, "[calls]: {} after sending {}", reason, sent.join(", "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for EtherCallsError {}
@@ -1,20 +1,166 @@
-use crate::ports::ether::ether_error_port::EtherErrorPort;
+use alloy_primitives::{Bytes, hex};
+use alloy_sol_types::{ContractError, GenericContractError, SolInterface, decode_revert_reason};
+use std::fmt;
 
-#[derive(serde::Serialize, serde::Deserialize)]
+use crate::{errors::error::Error, ports::ether::ether_error_port::EtherErrorPort};
+
+// Provider messages of a eth_getLogs query over the block range or results cap;
+const LOGS_LIMIT_MESSAGES: [&str; 6] = [
+    "query returned more than",
+    "log response size exceeded",
+    "block range is too wide",
+    "block range too large",
+    "exceed maximum block range",
+    "eth_getlogs is limited to",
+];
+
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
 pub struct EtherError {
+    #[serde(default)]
+    pub method: String,
+    #[serde(default)]
+    pub code: Option<i64>,
+    #[serde(default)]
     pub stack: String,
+    #[serde(default)]
     pub message: String,
+    #[serde(default)]
+    pub data: Option<serde_json::Value>,
 }
 
 impl EtherError {
     pub fn new_unknown() -> Self {
         Self {
             message: String::from("Something went wrong"),
-            stack: String::new(),
+            ..Default::default()
+        }
+    }
+
+    /*
+        Adapter errors are boxed `EtherError`s, this gives back the typed error.
+    */
+    pub fn from_error(error: &Error) -> Option<&Self> {
+        error.downcast_ref::<Self>()
+    }
+
+    /*
+        EIP-1193 `4001` the user rejected the request.
+    */
+    pub fn is_user_rejected(&self) -> bool {
+        self.code == Some(4001)
+    }
+
+    /*
+        EIP-1193 `4200` or JSON-RPC `-32601`, the wallet does not implement the method.
+    */
+    pub fn is_unsupported_method(&self) -> bool {
+        matches!(self.code, Some(4200) | Some(-32601))
+    }
+
+    /*
+        EIP-3085 `4902` chain not added to the wallet, some wallets nest it in `data.originalError`.
+    */
+    pub fn is_unrecognized_chain(&self) -> bool {
+        let nested = self
+            .data
+            .as_ref()
+            .and_then(|r| r.pointer("/originalError/code"))
+            .and_then(serde_json::Value::as_i64);
+
+        self.code == Some(4902) || nested == Some(4902)
+    }
+
+    /*
+        EIP-1474 `-32005` limit exceeded, or a provider specific range / results cap message
+        (Infura, Alchemy, QuickNode and geth style nodes), possibly nested in `data.originalError`.
+    */
+    pub fn is_logs_limit_exceeded(&self) -> bool {
+        let nested = self.data.as_ref().and_then(|r| r.pointer("/originalError"));
+
+        let code = nested
+            .and_then(|r| r.get("code"))
+            .and_then(serde_json::Value::as_i64);
+
+        if self.code == Some(-32005) || code == Some(-32005) {
+            return true;
+        }
+
+        let message = nested
+            .and_then(|r| r.get("message"))
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or(&self.message)
+            .to_lowercase();
+
+        LOGS_LIMIT_MESSAGES.iter().any(|i| message.contains(i))
+    }
+
+    /*
+        The call itself reverted: geth `3`, revert data, or a node message saying so.
+        Transport and wallet errors are not reverts.
+    */
+    pub fn is_execution_reverted(&self) -> bool {
+        self.code == Some(3)
+            || self.revert_data().is_some()
+            || self.message.to_lowercase().contains("revert")
+    }
+
+    /*
+        Wallets put the revert data in `data` as a hex string, or nest it as
+        `data.data`, `data.originalError.data` or `data.cause.data`.
+    */
+    pub fn revert_data(&self) -> Option<Bytes> {
+        Self::find_revert_data(self.data.as_ref()?)
+    }
+
+    /*
+        Human readable `Error(string)` reason or `Panic(uint256)` meaning.
+    */
+    pub fn revert_reason(&self) -> Option<String> {
+        let data = self.revert_data()?;
+
+        match GenericContractError::abi_decode(&data) {
+            Ok(ContractError::Revert(r)) => Some(r.reason().to_string()),
+            Ok(ContractError::Panic(r)) => Some(r.to_string()),
+            _ => decode_revert_reason(&data),
+        }
+    }
+
+    /*
+        Decode the revert against the contract errors, e.g. `ERC20::ERC20Errors`.
+    */
+    pub fn decode_revert<E: SolInterface>(&self) -> Option<ContractError<E>> {
+        ContractError::<E>::abi_decode(&self.revert_data()?).ok()
+    }
+
+    fn find_revert_data(value: &serde_json::Value) -> Option<Bytes> {
+        match value {
+            serde_json::Value::String(r) => {
+                let data = r.strip_prefix("0x")?;
+
+                match data.len() >= 8 {
+                    true => hex::decode(data).ok().map(Bytes::from),
+                    false => None,
+                }
+            }
+            serde_json::Value::Object(r) => ["data", "originalError", "cause"]
+                .iter()
+                .find_map(|i| Self::find_revert_data(r.get(*i)?)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for EtherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.revert_reason() {
+            Some(r) => write!(f, "[{}]: execution reverted: {}", self.method, r),
+            _ => write!(f, "[{}]: {}", self.method, self.message),
         }
     }
 }
 
+impl std::error::Error for EtherError {}
+
 impl EtherErrorPort for EtherError {
     fn message(&self) -> String {
         self.message.to_string()
@@ -23,4 +169,12 @@ impl EtherErrorPort for EtherError {
     fn stack(&self) -> String {
         self.stack.to_string()
     }
+
+    fn code(&self) -> Option<i64> {
+        self.code
+    }
+
+    fn data(&self) -> Option<serde_json::Value> {
+        self.data.clone()
+    }
 }
@@ -0,0 +1,26 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EtherUnitsError {
+    InvalidNumber(String),
+    TooManyDecimals { value: String, decimals: u8 },
+    Overflow(String),
+}
+
+impl fmt::Display for EtherUnitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNumber(r) => write!(f, "[units]: invalid number `{}`", r),
+            Self::TooManyDecimals { value, decimals } => {
+                write!(
+                    f,
+                    "[units]: `{}` has more than {} decimals",
+                    value, decimals
+                )
+            }
+            Self::Overflow(r) => write!(f, "[units]: `{}` does not fit into uint256", r),
+        }
+    }
+}
+
+impl std::error::Error for EtherUnitsError {}
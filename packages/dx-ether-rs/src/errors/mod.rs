@@ -1,2 +1,7 @@
+pub mod ens_error;
 pub mod error;
+pub mod ether_address_error;
+pub mod ether_calls_error;
 pub mod ether_error;
+pub mod ether_units_error;
+pub mod siwe_error;
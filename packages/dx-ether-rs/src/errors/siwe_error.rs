@@ -0,0 +1,43 @@
+use alloy_primitives::Address;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SiweError {
+    InvalidMessage(String),
+    InvalidField { field: String, reason: String },
+    DomainMismatch { expected: String, found: String },
+    NonceMismatch { expected: String, found: String },
+    ChainIdMismatch { expected: u64, found: u64 },
+    Expired(String),
+    NotYetValid(String),
+    InvalidSignature(Address),
+}
+
+impl fmt::Display for SiweError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMessage(r) => write!(f, "[siwe]: invalid message: {}", r),
+            Self::InvalidField { field, reason } => {
+                write!(f, "[siwe]: invalid {}: {}", field, reason)
+            }
+            Self::DomainMismatch { expected, found } => {
+                write!(
+                    f,
+                    "[siwe]: domain `{}` does not match `{}`",
+                    found, expected
+                )
+            }
+            Self::NonceMismatch { expected, found } => {
+                write!(f, "[siwe]: nonce `{}` does not match `{}`", found, expected)
+            }
+            Self::ChainIdMismatch { expected, found } => {
+                write!(f, "[siwe]: chain id {} does not match {}", found, expected)
+            }
+            Self::Expired(r) => write!(f, "[siwe]: message expired at {}", r),
+            Self::NotYetValid(r) => write!(f, "[siwe]: message not valid before {}", r),
+            Self::InvalidSignature(r) => write!(f, "[siwe]: signature is not valid for {}", r),
+        }
+    }
+}
+
+impl std::error::Error for SiweError {}
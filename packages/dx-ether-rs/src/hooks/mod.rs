@@ -1,12 +1,66 @@
+pub mod use_contract_events;
+pub mod use_contract_read;
+pub mod use_contract_write;
+pub mod use_ens_address;
+pub mod use_ens_avatar;
+pub mod use_ens_name;
+pub mod use_erc1155_balance;
+pub mod use_erc1155_balance_of_batch;
+pub mod use_erc1155_is_approved_for_all;
+pub mod use_erc1155_safe_batch_transfer_from;
+pub mod use_erc1155_safe_transfer_from;
+pub mod use_erc1155_set_approval_for_all;
+pub mod use_erc1155_uri;
+pub mod use_erc20_allowance;
+pub mod use_erc20_approve;
+pub mod use_erc20_balance;
+pub mod use_erc20_metadata;
+pub mod use_erc20_transfer;
+pub mod use_erc20_transfer_from;
+pub mod use_erc721_balance;
+pub mod use_erc721_is_approved_for_all;
+pub mod use_erc721_owner_of;
+pub mod use_erc721_safe_transfer_from;
+pub mod use_erc721_set_approval_for_all;
+pub mod use_erc721_token_uri;
 pub mod use_ether_accounts;
+pub mod use_ether_add_chain;
 pub mod use_ether_block_number;
 pub mod use_ether_call;
 pub mod use_ether_chain_id;
+pub mod use_ether_estimate_gas;
+pub mod use_ether_estimate_user_operation_gas;
 pub mod use_ether_gas_price;
 pub mod use_ether_get_balance;
+pub mod use_ether_get_block_by_hash;
+pub mod use_ether_get_block_by_number;
+pub mod use_ether_get_block_receipts;
+pub mod use_ether_get_calls_status;
+pub mod use_ether_get_capabilities;
+pub mod use_ether_get_code;
+pub mod use_ether_get_logs;
+pub mod use_ether_get_permissions;
+pub mod use_ether_get_storage_at;
+pub mod use_ether_get_transaction_by_block_number_and_index;
+pub mod use_ether_get_transaction_by_hash;
+pub mod use_ether_get_transaction_count;
 pub mod use_ether_get_transaction_receipt;
+pub mod use_ether_get_user_operation_receipt;
 pub mod use_ether_initialize;
+pub mod use_ether_max_priority_fee_per_gas;
 pub mod use_ether_personal_sign;
 pub mod use_ether_request_accounts;
+pub mod use_ether_request_permissions;
+pub mod use_ether_revoke_permissions;
+pub mod use_ether_send_calls;
 pub mod use_ether_send_transaction;
+pub mod use_ether_send_user_operation;
+pub mod use_ether_show_calls_status;
+pub mod use_ether_sign_typed_data;
 pub mod use_ether_switch_chain_id;
+pub mod use_ether_verify_signature;
+pub mod use_ether_watch_asset;
+pub mod use_multicall;
+pub mod use_nft_metadata;
+pub mod use_nft_standard;
+pub mod use_siwe_sign_in;
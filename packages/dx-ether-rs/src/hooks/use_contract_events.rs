@@ -0,0 +1,280 @@
+use alloy_primitives::Address;
+use alloy_sol_types::SolEvent;
+use dioxus::prelude::*;
+use gloo_timers::future::sleep;
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+use crate::{
+    adapters::ether_adapter::{EtherAdapter, EtherLog},
+    errors::{error::Error, ether_error::EtherError},
+    ports::ether::{
+        ether_get_filter_changes_port::EtherGetFilterChangesPort,
+        ether_get_logs_port::EtherGetLogsPort, ether_new_filter_port::EtherNewFilterPort,
+        ether_uninstall_filter_port::EtherUninstallFilterPort,
+    },
+    types::ether_log_types::EtherLogFilter,
+};
+
+#[derive(Clone)]
+pub struct ContractEvent<E> {
+    pub event: E,
+    pub log: EtherLog,
+}
+
+#[derive(Clone, Default)]
+pub struct UseContractEvents {
+    // Load history from this block on mount;
+    pub backfill_from_block: Option<String>,
+    // localStorage key to keep the next block to read between sessions;
+    pub cursor_key: Option<String>,
+    // Milliseconds between polls, 4000 by default;
+    pub poll_interval: Option<u64>,
+    // Blocks re-read on every getLogs poll to detect reorgs, 6 by default;
+    pub reorg_depth: Option<u64>,
+}
+
+/*
+    Watch events via eth_newFilter/eth_getFilterChanges, catching up with eth_getLogs and
+    re-creating the filter when it expires. Wallets without filters are polled with eth_getLogs.
+    The node side filter is uninstalled on unmount.
+*/
+pub fn use_contract_events<E: SolEvent + Clone + 'static>(
+    address: &Address,
+    filter: &EtherLogFilter,
+    options: &UseContractEvents,
+) -> (Signal<Vec<ContractEvent<E>>>, Signal<String>, Signal<bool>) {
+    let data = use_signal(Vec::<ContractEvent<E>>::new);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let filter = filter.clone().address(address).event::<E>();
+    let options = options.clone();
+    let filter_id = use_hook(|| Rc::new(RefCell::new(None::<String>)));
+
+    use_drop({
+        let filter_id = filter_id.clone();
+
+        move || {
+            if let Some(id) = filter_id.borrow_mut().take() {
+                spawn_forever(async move {
+                    let _ = EtherAdapter::new().eth_uninstall_filter(&id).await;
+                });
+            }
+        }
+    });
+
+    use_future(move || {
+        let filter = filter.clone();
+        let options = options.clone();
+        let filter_id = filter_id.clone();
+
+        async move {
+            loading.set(true);
+
+            if let Err(e) =
+                watch_contract_events(&filter, &options, filter_id, data, error, loading).await
+            {
+                error.set(e.to_string());
+                loading.set(false);
+            }
+        }
+    });
+
+    (data, error, loading)
+}
+
+async fn watch_contract_events<E: SolEvent + Clone + 'static>(
+    filter: &EtherLogFilter,
+    options: &UseContractEvents,
+    filter_id: Rc<RefCell<Option<String>>>,
+    mut data: Signal<Vec<ContractEvent<E>>>,
+    mut error: Signal<String>,
+    mut loading: Signal<bool>,
+) -> Result<(), Error> {
+    let adapter = EtherAdapter::new();
+    let interval = Duration::from_millis(options.poll_interval.unwrap_or(4_000));
+    let reorg_depth = options.reorg_depth.unwrap_or(6);
+
+    let latest = adapter.resolve_block_number(None).await?;
+
+    let stored = match &options.cursor_key {
+        Some(key) => adapter.storage_get(key).await?,
+        _ => None,
+    };
+
+    let backfill = match &options.backfill_from_block {
+        Some(block) => Some(adapter.resolve_block_number(Some(block)).await?),
+        _ => None,
+    };
+
+    let start = [stored.and_then(|r| r.parse::<u64>().ok()), backfill]
+        .into_iter()
+        .flatten()
+        .min();
+
+    if let Some(start) = start.filter(|r| *r <= latest) {
+        let history = filter
+            .clone()
+            .from_block(&format!("0x{:x}", start))
+            .to_block(&format!("0x{:x}", latest));
+
+        adapter
+            .eth_get_logs_paginated(&history, 10_000, |r| apply_logs(&mut data, r))
+            .await?;
+    }
+
+    let mut cursor = latest + 1;
+
+    save_cursor(&adapter, options, cursor).await?;
+
+    loading.set(false);
+
+    let mut filters_supported = true;
+    // A new filter only reports logs after `cursor`, so it waits until the logs are caught up;
+    let mut synced = true;
+
+    loop {
+        if filters_supported && synced && filter_id.borrow().is_none() {
+            match adapter
+                .eth_new_filter(&filter.clone().from_block(&format!("0x{:x}", cursor)))
+                .await
+            {
+                Ok(r) => *filter_id.borrow_mut() = Some(r),
+                Err(e) if EtherError::from_error(&e).is_some_and(|r| r.is_unsupported_method()) => {
+                    filters_supported = false
+                }
+                // Retried on the next tick;
+                Err(_) => {}
+            }
+        }
+
+        sleep(interval).await;
+
+        let id = filter_id.borrow().clone();
+
+        if let Some(id) = id {
+            match adapter.eth_get_filter_changes(&id).await {
+                Ok(r) => {
+                    if let Some(last) = r.iter().filter_map(block_number).max() {
+                        cursor = cursor.max(last + 1);
+                    }
+
+                    apply_logs(&mut data, r);
+
+                    if let Err(e) = save_cursor(&adapter, options, cursor).await {
+                        error.set(e.to_string());
+                    }
+
+                    continue;
+                }
+                Err(_) => {
+                    // Expired or dropped by the node, catch up below and install a new one;
+                    filter_id.borrow_mut().take();
+
+                    let _ = adapter.eth_uninstall_filter(&id).await;
+                }
+            }
+        }
+
+        match poll_logs(&adapter, filter, cursor, reorg_depth, &mut data).await {
+            Ok(r) => {
+                cursor = r;
+                synced = true;
+
+                if let Err(e) = save_cursor(&adapter, options, cursor).await {
+                    error.set(e.to_string());
+                }
+            }
+            Err(e) => {
+                synced = false;
+                error.set(e.to_string());
+            }
+        }
+    }
+}
+
+/*
+    Re-read the last `reorg_depth` blocks together with the new ones, so logs dropped by a reorg
+    disappear from the list. Returns the next block to read.
+*/
+async fn poll_logs<E: SolEvent + Clone + 'static>(
+    adapter: &EtherAdapter,
+    filter: &EtherLogFilter,
+    cursor: u64,
+    reorg_depth: u64,
+    data: &mut Signal<Vec<ContractEvent<E>>>,
+) -> Result<u64, Error> {
+    let latest = adapter.resolve_block_number(None).await?;
+
+    if latest < cursor {
+        return Ok(cursor);
+    }
+
+    let from = cursor.saturating_sub(reorg_depth);
+
+    let logs = adapter
+        .eth_get_logs(
+            &filter
+                .clone()
+                .from_block(&format!("0x{:x}", from))
+                .to_block(&format!("0x{:x}", latest)),
+        )
+        .await?;
+
+    data.write().retain(|i| {
+        block_number(&i.log).is_none_or(|r| r < from) || logs.iter().any(|l| same_log(l, &i.log))
+    });
+
+    apply_logs(data, logs);
+
+    Ok(latest + 1)
+}
+
+fn apply_logs<E: SolEvent + Clone + 'static>(
+    data: &mut Signal<Vec<ContractEvent<E>>>,
+    logs: Vec<EtherLog>,
+) {
+    let mut data = data.write();
+
+    for log in logs {
+        if log.removed {
+            data.retain(|i| !same_log(&i.log, &log));
+            continue;
+        }
+
+        if data.iter().any(|i| same_log(&i.log, &log)) {
+            continue;
+        }
+
+        let Ok(decoded) = log.to_log() else {
+            continue;
+        };
+
+        if let Ok(r) = E::decode_log(&decoded) {
+            data.push(ContractEvent { event: r.data, log });
+        }
+    }
+}
+
+async fn save_cursor(
+    adapter: &EtherAdapter,
+    options: &UseContractEvents,
+    cursor: u64,
+) -> Result<(), Error> {
+    match &options.cursor_key {
+        Some(key) => adapter.storage_set(key, &cursor.to_string()).await,
+        _ => Ok(()),
+    }
+}
+
+fn same_log(a: &EtherLog, b: &EtherLog) -> bool {
+    a.transaction_hash == b.transaction_hash
+        && a.log_index == b.log_index
+        && a.block_hash == b.block_hash
+}
+
+fn block_number(log: &EtherLog) -> Option<u64> {
+    let block = log.block_number.as_deref()?;
+
+    u64::from_str_radix(block.trim_start_matches("0x"), 16).ok()
+}
@@ -0,0 +1,62 @@
+use alloy_primitives::Address;
+use alloy_sol_types::{SolCall, SolInterface};
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::contract_adapter::Contract, errors::ether_error::EtherError,
+    ports::contract_port::ContractPort,
+};
+
+// Decoded return, error message, loading and the typed error;
+pub type UseContractReadResult<R> = (
+    Signal<Option<R>>,
+    Signal<String>,
+    Signal<bool>,
+    Signal<Option<EtherError>>,
+);
+
+pub fn use_contract_read<I, C>(
+    address: Address,
+    call: C,
+    block: Option<String>,
+    activated: Option<Signal<bool>>,
+) -> UseContractReadResult<C::Return>
+where
+    I: SolInterface + 'static,
+    C: SolCall + Clone + 'static,
+    C::Return: 'static,
+{
+    let mut data = use_signal(|| None::<C::Return>);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+    let mut ether_error = use_signal(|| None::<EtherError>);
+
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let call = call.clone();
+            let block = block.clone();
+
+            spawn(async move {
+                match Contract::<I>::new(address).read(call, block).await {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(Some(r));
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        ether_error.set(EtherError::from_error(&e).cloned());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading, ether_error)
+}
@@ -0,0 +1,59 @@
+use alloy_primitives::Address;
+use alloy_sol_types::{SolCall, SolInterface};
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::contract_adapter::Contract, errors::ether_error::EtherError,
+    ports::contract_port::ContractPort, types::contract_types::ContractTransactionOptions,
+};
+
+pub fn use_contract_write<I, C>(
+    address: Address,
+    call: C,
+    options: &ContractTransactionOptions,
+    activated: Option<Signal<bool>>,
+) -> (
+    Signal<String>,
+    Signal<String>,
+    Signal<bool>,
+    Signal<Option<EtherError>>,
+)
+where
+    I: SolInterface + 'static,
+    C: SolCall + Clone + 'static,
+{
+    let mut data = use_signal(String::new);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+    let mut ether_error = use_signal(|| None::<EtherError>);
+
+    let options = options.clone();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let call = call.clone();
+            let options = options.clone();
+
+            spawn(async move {
+                match Contract::<I>::new(address).write(call, &options).await {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        ether_error.set(EtherError::from_error(&e).cloned());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading, ether_error)
+}
@@ -0,0 +1,53 @@
+use alloy_primitives::Address;
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ens_adapter::EnsAdapter, ports::ens_port::EnsPort,
+    types::ccip_read_types::CcipReadOptions,
+};
+
+/*
+    Address of an ENS name, wildcard (ENSIP-10) names included.
+*/
+pub fn use_ens_address(
+    name: &str,
+    ccip_read: Option<CcipReadOptions>,
+    activated: Option<Signal<bool>>,
+) -> (Signal<Option<Address>>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(|| None::<Address>);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let name = name.to_string();
+    let ccip_read = ccip_read.unwrap_or_default();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let name = name.clone();
+            let ccip_read = ccip_read.clone();
+
+            spawn(async move {
+                match EnsAdapter::with_ccip_read(ccip_read)
+                    .resolve_name(&name)
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,49 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ens_adapter::EnsAdapter, ports::ens_port::EnsPort,
+    types::ccip_read_types::CcipReadOptions,
+};
+
+/*
+    ENSIP-12 avatar of an ENS name, ready to use as `img` src.
+*/
+pub fn use_ens_avatar(
+    name: &str,
+    ccip_read: Option<CcipReadOptions>,
+    activated: Option<Signal<bool>>,
+) -> (Signal<Option<String>>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(|| None::<String>);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let name = name.to_string();
+    let ccip_read = ccip_read.unwrap_or_default();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let name = name.clone();
+            let ccip_read = ccip_read.clone();
+
+            spawn(async move {
+                match EnsAdapter::with_ccip_read(ccip_read).avatar(&name).await {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,52 @@
+use alloy_primitives::Address;
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ens_adapter::EnsAdapter, ports::ens_port::EnsPort,
+    types::ccip_read_types::CcipReadOptions,
+};
+
+/*
+    Primary ENS name of an address, `None` unless it resolves back to the same address.
+*/
+pub fn use_ens_name(
+    address: &Address,
+    ccip_read: Option<CcipReadOptions>,
+    activated: Option<Signal<bool>>,
+) -> (Signal<Option<String>>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(|| None::<String>);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let address = *address;
+    let ccip_read = ccip_read.unwrap_or_default();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let ccip_read = ccip_read.clone();
+
+            spawn(async move {
+                match EnsAdapter::with_ccip_read(ccip_read)
+                    .lookup_address(&address)
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,40 @@
+use alloy_primitives::{Address, U256};
+use dioxus::prelude::*;
+
+use crate::{adapters::erc1155_adapter::Erc1155Adapter, ports::erc1155_port::Erc1155Port};
+
+pub fn use_erc1155_balance(
+    token: Address,
+    owner: Address,
+    id: U256,
+    activated: Option<Signal<bool>>,
+) -> (Signal<U256>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(U256::default);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            spawn(async move {
+                match Erc1155Adapter::new(token).balance_of(owner, id).await {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,48 @@
+use alloy_primitives::{Address, U256};
+use dioxus::prelude::*;
+
+use crate::{adapters::erc1155_adapter::Erc1155Adapter, ports::erc1155_port::Erc1155Port};
+
+pub fn use_erc1155_balance_of_batch(
+    token: Address,
+    owners: &[Address],
+    ids: &[U256],
+    activated: Option<Signal<bool>>,
+) -> (Signal<Vec<U256>>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(Vec::<U256>::new);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let owners = owners.to_vec();
+    let ids = ids.to_vec();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let owners = owners.clone();
+            let ids = ids.clone();
+
+            spawn(async move {
+                match Erc1155Adapter::new(token)
+                    .balance_of_batch(&owners, &ids)
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
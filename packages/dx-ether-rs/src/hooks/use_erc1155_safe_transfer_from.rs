@@ -0,0 +1,62 @@
+use alloy_primitives::{Address, U256};
+use dioxus::prelude::*;
+
+use crate::{adapters::erc1155_adapter::Erc1155Adapter, ports::erc1155_port::Erc1155Port};
+
+#[derive(Clone)]
+pub struct UseErc1155SafeTransferFrom {
+    pub token: Address,
+    pub from: Address,
+    pub owner: Address,
+    pub to: Address,
+    pub id: U256,
+    pub amount: U256,
+    pub data: Vec<u8>,
+    pub activated: Option<Signal<bool>>,
+}
+
+pub fn use_erc1155_safe_transfer_from(
+    payload: &UseErc1155SafeTransferFrom,
+) -> (Signal<String>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(String::new);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let payload = payload.clone();
+    let activated = payload.activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let payload = payload.clone();
+
+            spawn(async move {
+                match Erc1155Adapter::new(payload.token)
+                    .safe_transfer_from(
+                        payload.from,
+                        payload.owner,
+                        payload.to,
+                        payload.id,
+                        payload.amount,
+                        payload.data,
+                    )
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,52 @@
+use alloy_primitives::Address;
+use dioxus::prelude::*;
+
+use crate::{adapters::erc1155_adapter::Erc1155Adapter, ports::erc1155_port::Erc1155Port};
+
+#[derive(Clone)]
+pub struct UseErc1155SetApprovalForAll {
+    pub token: Address,
+    pub from: Address,
+    pub operator: Address,
+    pub approved: bool,
+    pub activated: Option<Signal<bool>>,
+}
+
+pub fn use_erc1155_set_approval_for_all(
+    payload: &UseErc1155SetApprovalForAll,
+) -> (Signal<String>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(String::new);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let payload = payload.clone();
+    let activated = payload.activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let payload = payload.clone();
+
+            spawn(async move {
+                match Erc1155Adapter::new(payload.token)
+                    .set_approval_for_all(payload.from, payload.operator, payload.approved)
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,43 @@
+use alloy_primitives::Address;
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::erc20_adapter::Erc20Adapter, ports::erc20_port::Erc20Port,
+    types::erc20_types::Erc20Amount,
+};
+
+pub fn use_erc20_allowance(
+    token: Address,
+    owner: Address,
+    spender: Address,
+    activated: Option<Signal<bool>>,
+) -> (Signal<Erc20Amount>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(Erc20Amount::default);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            spawn(async move {
+                match Erc20Adapter::new(token).allowance(owner, spender).await {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,53 @@
+use alloy_primitives::{Address, U256};
+use dioxus::prelude::*;
+
+use crate::{adapters::erc20_adapter::Erc20Adapter, ports::erc20_port::Erc20Port};
+
+#[derive(Clone)]
+pub struct UseErc20TransferFrom {
+    pub token: Address,
+    pub from: Address,
+    pub owner: Address,
+    pub to: Address,
+    pub amount: U256,
+    pub activated: Option<Signal<bool>>,
+}
+
+pub fn use_erc20_transfer_from(
+    payload: &UseErc20TransferFrom,
+) -> (Signal<String>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(String::new);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let payload = payload.clone();
+    let activated = payload.activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let payload = payload.clone();
+
+            spawn(async move {
+                match Erc20Adapter::new(payload.token)
+                    .transfer_from(payload.from, payload.owner, payload.to, payload.amount)
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,39 @@
+use alloy_primitives::{Address, U256};
+use dioxus::prelude::*;
+
+use crate::{adapters::erc721_adapter::Erc721Adapter, ports::erc721_port::Erc721Port};
+
+pub fn use_erc721_balance(
+    token: Address,
+    owner: Address,
+    activated: Option<Signal<bool>>,
+) -> (Signal<U256>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(U256::default);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            spawn(async move {
+                match Erc721Adapter::new(token).balance_of(owner).await {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
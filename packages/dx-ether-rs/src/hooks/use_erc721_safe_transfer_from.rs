@@ -0,0 +1,60 @@
+use alloy_primitives::{Address, U256};
+use dioxus::prelude::*;
+
+use crate::{adapters::erc721_adapter::Erc721Adapter, ports::erc721_port::Erc721Port};
+
+#[derive(Clone)]
+pub struct UseErc721SafeTransferFrom {
+    pub token: Address,
+    pub from: Address,
+    pub owner: Address,
+    pub to: Address,
+    pub token_id: U256,
+    pub data: Vec<u8>,
+    pub activated: Option<Signal<bool>>,
+}
+
+pub fn use_erc721_safe_transfer_from(
+    payload: &UseErc721SafeTransferFrom,
+) -> (Signal<String>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(String::new);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let payload = payload.clone();
+    let activated = payload.activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let payload = payload.clone();
+
+            spawn(async move {
+                match Erc721Adapter::new(payload.token)
+                    .safe_transfer_from(
+                        payload.from,
+                        payload.owner,
+                        payload.to,
+                        payload.token_id,
+                        payload.data,
+                    )
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -1,12 +1,13 @@
+use alloy_primitives::Address;
 use dioxus::prelude::*;
 
 use crate::{
     adapters::ether_adapter::EtherAdapter, ports::ether::ether_accounts_port::EtherAccountsPort,
 };
 
-pub fn use_ether_accounts() -> (Signal<Vec<String>>, Signal<String>, Signal<bool>) {
-    let mut data = use_signal(|| Vec::<String>::new());
-    let mut error = use_signal(|| String::new());
+pub fn use_ether_accounts() -> (Signal<Vec<Address>>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(Vec::<Address>::new);
+    let mut error = use_signal(String::new);
     let mut loading = use_signal(|| false);
 
     use_future(move || async move {
@@ -0,0 +1,42 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ether_adapter::EtherAdapter,
+    ports::ether::wallet_add_ethereum_chain_port::WalletAddEthereumChainPort,
+    types::ether_chain_types::EtherChainParameters,
+};
+
+pub fn use_ether_add_chain(
+    chain: &EtherChainParameters,
+    activated: Option<Signal<bool>>,
+) -> (Signal<String>, Signal<bool>) {
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let chain = chain.clone();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let chain = chain.clone();
+
+            spawn(async move {
+                match EtherAdapter::new().wallet_add_ethereum_chain(&chain).await {
+                    Ok(_) => {
+                        loading.set(false);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (error, loading)
+}
@@ -7,7 +7,7 @@ use crate::{
 
 pub fn use_ether_block_number() -> (Signal<String>, Signal<String>, Signal<bool>) {
     let mut data = use_signal(String::new);
-    let mut error = use_signal(|| String::new());
+    let mut error = use_signal(String::new);
     let mut loading = use_signal(|| false);
 
     use_future(move || async move {
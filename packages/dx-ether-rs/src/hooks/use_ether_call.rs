@@ -1,20 +1,31 @@
+use alloy_primitives::Address;
 use dioxus::prelude::*;
 
-use crate::{adapters::ether_adapter::EtherAdapter, ports::ether::ether_call_port::EtherCallPort};
+use crate::{
+    adapters::ccip_read_adapter::CcipReadAdapter, errors::ether_error::EtherError,
+    ports::ether::ether_call_port::EtherCallPort, types::ccip_read_types::CcipReadOptions,
+};
 
 pub fn use_ether_call(
-    address: &str,
+    address: Address,
     calldata: &str,
     block: Option<String>,
+    ccip_read: Option<CcipReadOptions>,
     activated: Option<Signal<bool>>,
-) -> (Signal<String>, Signal<String>, Signal<bool>) {
+) -> (
+    Signal<String>,
+    Signal<String>,
+    Signal<bool>,
+    Signal<Option<EtherError>>,
+) {
     let mut data = use_signal(String::new);
-    let mut error = use_signal(|| String::new());
+    let mut error = use_signal(String::new);
     let mut loading = use_signal(|| false);
+    let mut ether_error = use_signal(|| None::<EtherError>);
 
-    let address = address.to_string();
     let calldata = calldata.to_string();
     let block = block.unwrap_or("latest".to_string());
+    let ccip_read = ccip_read.unwrap_or_default();
     let activated = activated.unwrap_or(Signal::new(false));
 
     use_effect(move || {
@@ -23,13 +34,14 @@ pub fn use_ether_call(
         if activated {
             loading.set(true);
 
-            let address = address.to_string();
             let calldata = calldata.to_string();
             let block = block.to_string();
+            let ccip_read = ccip_read.clone();
 
             spawn(async move {
-                match EtherAdapter::new()
-                    .eth_call(&address, &calldata, Some(block))
+                match CcipReadAdapter::new()
+                    .options(ccip_read)
+                    .eth_call(address, &calldata, Some(block))
                     .await
                 {
                     Ok(r) => {
@@ -38,6 +50,7 @@ pub fn use_ether_call(
                     }
                     Err(e) => {
                         error.set(e.to_string());
+                        ether_error.set(EtherError::from_error(&e).cloned());
                         loading.set(false);
                     }
                 };
@@ -45,5 +58,5 @@ pub fn use_ether_call(
         }
     });
 
-    (data, error, loading)
+    (data, error, loading, ether_error)
 }
@@ -0,0 +1,62 @@
+use alloy_primitives::Address;
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ether_adapter::EtherAdapter, errors::ether_error::EtherError,
+    ports::ether::ether_estimate_gas_port::EtherEstimateGasPort,
+};
+
+#[derive(Clone)]
+pub struct UseEtherEstimateGas {
+    pub from: Option<Address>,
+    pub to: Address,
+    pub value: Option<String>,
+    pub calldata: Option<String>,
+    pub activated: Option<Signal<bool>>,
+}
+
+pub fn use_ether_estimate_gas(
+    payload: &UseEtherEstimateGas,
+) -> (
+    Signal<String>,
+    Signal<String>,
+    Signal<bool>,
+    Signal<Option<EtherError>>,
+) {
+    let mut data = use_signal(String::new);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+    let mut ether_error = use_signal(|| None::<EtherError>);
+
+    let payload = payload.clone();
+    let activated = payload.activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let payload = payload.clone();
+
+            spawn(async move {
+                match EtherAdapter::new()
+                    .eth_estimate_gas(payload.from, payload.to, payload.value, payload.calldata)
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        ether_error.set(EtherError::from_error(&e).cloned());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading, ether_error)
+}
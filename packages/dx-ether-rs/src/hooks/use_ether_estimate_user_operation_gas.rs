@@ -0,0 +1,54 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::user_operation_adapter::BundlerAdapter,
+    ports::bundler_port::BundlerPort,
+    types::user_operation_types::{EtherUserOperationGas, UserOperation},
+};
+
+#[derive(Clone)]
+pub struct UseEtherEstimateUserOperationGas {
+    pub bundler_url: String,
+    // Needs a dummy signature of the account shape;
+    pub user_operation: UserOperation,
+    pub activated: Option<Signal<bool>>,
+}
+
+pub fn use_ether_estimate_user_operation_gas(
+    payload: &UseEtherEstimateUserOperationGas,
+) -> (Signal<EtherUserOperationGas>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(EtherUserOperationGas::default);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let payload = payload.clone();
+    let activated = payload.activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let payload = payload.clone();
+
+            spawn(async move {
+                match BundlerAdapter::new(&payload.bundler_url)
+                    .estimate_user_operation_gas(&payload.user_operation)
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -6,7 +6,7 @@ use crate::{
 
 pub fn use_ether_gas_price() -> (Signal<String>, Signal<String>, Signal<bool>) {
     let mut data = use_signal(String::new);
-    let mut error = use_signal(|| String::new());
+    let mut error = use_signal(String::new);
     let mut loading = use_signal(|| false);
 
     use_future(move || async move {
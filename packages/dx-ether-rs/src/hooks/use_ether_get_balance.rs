@@ -1,3 +1,4 @@
+use alloy_primitives::Address;
 use dioxus::prelude::*;
 
 use crate::{
@@ -6,15 +7,14 @@ use crate::{
 };
 
 pub fn use_ether_get_balance(
-    address: &str,
+    address: Address,
     block: Option<String>,
     activated: Option<Signal<bool>>,
 ) -> (Signal<String>, Signal<String>, Signal<bool>) {
     let mut data = use_signal(String::new);
-    let mut error = use_signal(|| String::new());
+    let mut error = use_signal(String::new);
     let mut loading = use_signal(|| false);
 
-    let address = address.to_string();
     let block = block.unwrap_or("latest".to_string());
     let activated = activated.unwrap_or(Signal::new(false));
 
@@ -24,12 +24,11 @@ pub fn use_ether_get_balance(
         if activated {
             loading.set(true);
 
-            let address = address.to_string();
             let block = block.to_string();
 
             spawn(async move {
                 match EtherAdapter::new()
-                    .eth_get_balance(&address, Some(block))
+                    .eth_get_balance(address, Some(block))
                     .await
                 {
                     Ok(r) => {
@@ -0,0 +1,47 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ether_adapter::{EtherAdapter, EtherBlock},
+    ports::ether::ether_get_block_by_number_port::EtherGetBlockByNumberPort,
+};
+
+pub fn use_ether_get_block_by_number(
+    block: &str,
+    full_transactions: bool,
+    activated: Option<Signal<bool>>,
+) -> (Signal<Option<EtherBlock>>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(|| None::<EtherBlock>);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let block = block.to_string();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let block = block.to_string();
+
+            spawn(async move {
+                match EtherAdapter::new()
+                    .eth_get_block_by_number(&block, full_transactions)
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,48 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ether_adapter::EtherAdapter,
+    ports::ether::wallet_get_calls_status_port::WalletGetCallsStatusPort,
+    types::ether_calls_types::EtherCallsStatus,
+};
+
+pub fn use_ether_get_calls_status(
+    id: &str,
+    activated: Option<Signal<bool>>,
+) -> (
+    Signal<Option<EtherCallsStatus>>,
+    Signal<String>,
+    Signal<bool>,
+) {
+    let mut data = use_signal(|| None::<EtherCallsStatus>);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let id = id.to_string();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let id = id.clone();
+
+            spawn(async move {
+                match EtherAdapter::new().wallet_get_calls_status(&id).await {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(Some(r));
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,59 @@
+use alloy_primitives::Address;
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+use crate::{
+    adapters::ether_adapter::EtherAdapter,
+    ports::ether::wallet_get_capabilities_port::WalletGetCapabilitiesPort,
+    types::ether_calls_types::EtherCapabilities,
+};
+
+/*
+    Capabilities keyed by `0x` chain id, an empty `chain_ids` asks for every chain.
+*/
+pub fn use_ether_get_capabilities(
+    address: Address,
+    chain_ids: &[&str],
+    activated: Option<Signal<bool>>,
+) -> (
+    Signal<HashMap<String, EtherCapabilities>>,
+    Signal<String>,
+    Signal<bool>,
+) {
+    let mut data = use_signal(HashMap::<String, EtherCapabilities>::new);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let chain_ids = chain_ids.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let chain_ids = chain_ids.clone();
+
+            spawn(async move {
+                let chain_ids = chain_ids.iter().map(String::as_str).collect::<Vec<_>>();
+
+                match EtherAdapter::new()
+                    .wallet_get_capabilities(address, &chain_ids)
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
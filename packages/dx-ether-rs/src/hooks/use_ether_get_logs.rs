@@ -0,0 +1,51 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ether_adapter::{EtherAdapter, EtherLog},
+    types::ether_log_types::EtherLogFilter,
+};
+
+/*
+    Logs are appended chunk by chunk, so the view renders results while the range is still loading.
+*/
+pub fn use_ether_get_logs(
+    filter: &EtherLogFilter,
+    chunk_size: Option<u64>,
+    activated: Option<Signal<bool>>,
+) -> (Signal<Vec<EtherLog>>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(Vec::<EtherLog>::new);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let filter = filter.clone();
+    let chunk_size = chunk_size.unwrap_or(10_000);
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+            data.set(Vec::new());
+
+            let filter = filter.clone();
+
+            spawn(async move {
+                match EtherAdapter::new()
+                    .eth_get_logs_paginated(&filter, chunk_size, |r| data.write().extend(r))
+                    .await
+                {
+                    Ok(_) => {
+                        loading.set(false);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,38 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ether_adapter::EtherAdapter,
+    ports::ether::wallet_get_permissions_port::WalletGetPermissionsPort,
+    types::ether_permission_types::EtherPermission,
+};
+
+pub fn use_ether_get_permissions(
+    activated: Option<Signal<bool>>,
+) -> (Signal<Vec<EtherPermission>>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(Vec::<EtherPermission>::new);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        if activated() {
+            loading.set(true);
+
+            spawn(async move {
+                match EtherAdapter::new().wallet_get_permissions().await {
+                    Ok(r) => {
+                        data.set(r);
+                        loading.set(false);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,53 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ether_adapter::{EtherAdapter, EtherTransaction},
+    ports::ether::ether_get_transaction_by_block_number_and_index_port::EtherGetTransactionByBlockNumberAndIndexPort,
+};
+
+pub fn use_ether_get_transaction_by_block_number_and_index(
+    block: &str,
+    index: &str,
+    activated: Option<Signal<bool>>,
+) -> (
+    Signal<Option<EtherTransaction>>,
+    Signal<String>,
+    Signal<bool>,
+) {
+    let mut data = use_signal(|| None::<EtherTransaction>);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let block = block.to_string();
+    let index = index.to_string();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let block = block.to_string();
+            let index = index.to_string();
+
+            spawn(async move {
+                match EtherAdapter::new()
+                    .eth_get_transaction_by_block_number_and_index(&block, &index)
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,50 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ether_adapter::{EtherAdapter, EtherTransaction},
+    ports::ether::ether_get_transaction_by_hash_port::EtherGetTransactionByHashPort,
+};
+
+pub fn use_ether_get_transaction_by_hash(
+    transaction_hash: &str,
+    activated: Option<Signal<bool>>,
+) -> (
+    Signal<Option<EtherTransaction>>,
+    Signal<String>,
+    Signal<bool>,
+) {
+    let mut data = use_signal(|| None::<EtherTransaction>);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let transaction_hash = transaction_hash.to_string();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let transaction_hash = transaction_hash.to_string();
+
+            spawn(async move {
+                match EtherAdapter::new()
+                    .eth_get_transaction_by_hash(&transaction_hash)
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
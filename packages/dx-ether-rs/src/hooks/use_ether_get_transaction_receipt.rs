@@ -13,7 +13,7 @@ pub fn use_ether_get_transaction_receipt(
     Signal<bool>,
 ) {
     let mut data = use_signal(EtherTransactionReceipt::default);
-    let mut error = use_signal(|| String::new());
+    let mut error = use_signal(String::new);
     let mut loading = use_signal(|| false);
 
     let transaction_hash = transaction_hash.to_string();
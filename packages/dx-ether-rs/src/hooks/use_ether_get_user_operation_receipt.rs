@@ -0,0 +1,56 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::user_operation_adapter::BundlerAdapter, ports::bundler_port::BundlerPort,
+    types::user_operation_types::EtherUserOperationReceipt,
+};
+
+/*
+    Polls the bundler until the user operation is included.
+*/
+pub fn use_ether_get_user_operation_receipt(
+    bundler_url: &str,
+    user_operation_hash: &str,
+    activated: Option<Signal<bool>>,
+) -> (
+    Signal<Option<EtherUserOperationReceipt>>,
+    Signal<String>,
+    Signal<bool>,
+) {
+    let mut data = use_signal(|| None::<EtherUserOperationReceipt>);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let bundler_url = bundler_url.to_string();
+    let user_operation_hash = user_operation_hash.to_string();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let bundler_url = bundler_url.clone();
+            let user_operation_hash = user_operation_hash.clone();
+
+            spawn(async move {
+                match BundlerAdapter::new(&bundler_url)
+                    .wait_user_operation_receipt(&user_operation_hash)
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(Some(r));
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
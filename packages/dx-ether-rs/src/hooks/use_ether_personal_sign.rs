@@ -1,32 +1,43 @@
+use alloy_primitives::Address;
 use dioxus::prelude::*;
 
 use crate::{
-    adapters::ether_adapter::EtherAdapter, ports::ether::personal_sign_port::PersonalSignPort,
+    adapters::ether_adapter::EtherAdapter,
+    ports::ether::personal_sign_port::PersonalSignPort,
+    types::personal_sign_types::{EtherPersonalSignMessage, EtherPersonalSignature},
 };
 
+#[derive(Clone)]
+pub struct UseEtherPersonalSign {
+    pub address: Address,
+    // `"text".into()` or raw bytes, e.g. `B256::into()`;
+    pub message: EtherPersonalSignMessage,
+    pub activated: Option<Signal<bool>>,
+}
+
 pub fn use_ether_personal_sign(
-    message: &str,
-    address: &str,
-    activated: Option<Signal<bool>>,
-) -> (Signal<String>, Signal<String>, Signal<bool>) {
-    let mut data = use_signal(String::new);
-    let mut error = use_signal(|| String::new());
+    payload: &UseEtherPersonalSign,
+) -> (Signal<EtherPersonalSignature>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(EtherPersonalSignature::default);
+    let mut error = use_signal(String::new);
     let mut loading = use_signal(|| false);
 
-    let message = message.to_string();
-    let address = address.to_string();
-    let activated = activated.unwrap_or(Signal::new(false));
+    let payload = payload.clone();
+    let activated = payload.activated.unwrap_or(Signal::new(false));
 
     use_effect(move || {
         let activated = activated();
-        let message = message.to_string();
-        let address = address.to_string();
 
-        spawn(async move {
-            if activated {
-                loading.set(true);
+        if activated {
+            loading.set(true);
+
+            let payload = payload.clone();
 
-                match EtherAdapter::new().personal_sign(&message, &address).await {
+            spawn(async move {
+                match EtherAdapter::new()
+                    .personal_sign(&payload.message, &payload.address)
+                    .await
+                {
                     Ok(r) => {
                         loading.set(false);
                         data.set(r);
@@ -36,8 +47,8 @@ pub fn use_ether_personal_sign(
                         loading.set(false);
                     }
                 };
-            }
-        });
+            });
+        }
     });
 
     (data, error, loading)
@@ -1,3 +1,4 @@
+use alloy_primitives::Address;
 use dioxus::prelude::*;
 
 use crate::{
@@ -7,9 +8,9 @@ use crate::{
 
 pub fn use_ether_request_accounts(
     activated: Option<Signal<bool>>,
-) -> (Signal<Vec<String>>, Signal<String>, Signal<bool>) {
-    let mut data = use_signal(|| Vec::<String>::new());
-    let mut error = use_signal(|| String::new());
+) -> (Signal<Vec<Address>>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(Vec::<Address>::new);
+    let mut error = use_signal(String::new);
     let mut loading = use_signal(|| false);
 
     let activated = activated.unwrap_or(Signal::new(false));
@@ -0,0 +1,53 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ether_adapter::EtherAdapter,
+    ports::ether::wallet_request_permissions_port::WalletRequestPermissionsPort,
+    types::ether_permission_types::EtherPermission,
+};
+
+/*
+    Requesting `eth_accounts` again opens the wallet account picker.
+*/
+pub fn use_ether_request_permissions(
+    capabilities: &[&str],
+    activated: Option<Signal<bool>>,
+) -> (Signal<Vec<EtherPermission>>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(Vec::<EtherPermission>::new);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let capabilities = capabilities
+        .iter()
+        .map(|r| r.to_string())
+        .collect::<Vec<_>>();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        if activated() {
+            loading.set(true);
+
+            let capabilities = capabilities.clone();
+
+            spawn(async move {
+                let capabilities = capabilities.iter().map(String::as_str).collect::<Vec<_>>();
+
+                match EtherAdapter::new()
+                    .wallet_request_permissions(&capabilities)
+                    .await
+                {
+                    Ok(r) => {
+                        data.set(r);
+                        loading.set(false);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,50 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ether_adapter::EtherAdapter,
+    ports::ether::wallet_revoke_permissions_port::WalletRevokePermissionsPort,
+};
+
+/*
+    Revoking `eth_accounts` disconnects the site in the wallet.
+*/
+pub fn use_ether_revoke_permissions(
+    capabilities: &[&str],
+    activated: Option<Signal<bool>>,
+) -> (Signal<String>, Signal<bool>) {
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let capabilities = capabilities
+        .iter()
+        .map(|r| r.to_string())
+        .collect::<Vec<_>>();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        if activated() {
+            loading.set(true);
+
+            let capabilities = capabilities.clone();
+
+            spawn(async move {
+                let capabilities = capabilities.iter().map(String::as_str).collect::<Vec<_>>();
+
+                match EtherAdapter::new()
+                    .wallet_revoke_permissions(&capabilities)
+                    .await
+                {
+                    Ok(_) => {
+                        loading.set(false);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (error, loading)
+}
@@ -0,0 +1,49 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ether_calls_adapter::EtherCallsAdapter,
+    types::ether_calls_types::{EtherSendCalls, EtherSentCalls},
+};
+
+#[derive(Clone)]
+pub struct UseEtherSendCalls {
+    // Sent one by one with `eth_sendTransaction` when the wallet lacks the capabilities;
+    pub calls: EtherSendCalls,
+    pub activated: Option<Signal<bool>>,
+}
+
+pub fn use_ether_send_calls(
+    payload: &UseEtherSendCalls,
+) -> (Signal<Option<EtherSentCalls>>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(|| None::<EtherSentCalls>);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let payload = payload.clone();
+    let activated = payload.activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let payload = payload.clone();
+
+            spawn(async move {
+                match EtherCallsAdapter::new().send_calls(&payload.calls).await {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(Some(r));
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
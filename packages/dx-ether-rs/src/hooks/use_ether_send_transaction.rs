@@ -1,14 +1,15 @@
+use alloy_primitives::Address;
 use dioxus::prelude::*;
 
 use crate::{
-    adapters::ether_adapter::EtherAdapter,
+    adapters::ether_adapter::EtherAdapter, errors::ether_error::EtherError,
     ports::ether::ether_send_transaction_port::EtherSendTransactionPort,
 };
 
 #[derive(Clone)]
 pub struct UseEtherSendTransaction {
-    pub from: String,
-    pub to: String,
+    pub from: Address,
+    pub to: Address,
     pub value: Option<String>,
     pub calldata: Option<String>,
     pub gas: Option<String>,
@@ -18,10 +19,16 @@ pub struct UseEtherSendTransaction {
 
 pub fn use_ether_send_transaction(
     payload: &UseEtherSendTransaction,
-) -> (Signal<String>, Signal<String>, Signal<bool>) {
+) -> (
+    Signal<String>,
+    Signal<String>,
+    Signal<bool>,
+    Signal<Option<EtherError>>,
+) {
     let mut data = use_signal(String::new);
-    let mut error = use_signal(|| String::new());
+    let mut error = use_signal(String::new);
     let mut loading = use_signal(|| false);
+    let mut ether_error = use_signal(|| None::<EtherError>);
 
     let payload = payload.clone();
     let activated = payload.activated.unwrap_or(Signal::new(false));
@@ -37,8 +44,8 @@ pub fn use_ether_send_transaction(
             spawn(async move {
                 match EtherAdapter::new()
                     .eth_send_transaction(
-                        &payload.from,
-                        &payload.to,
+                        payload.from,
+                        payload.to,
                         payload.value,
                         payload.calldata,
                         payload.gas,
@@ -52,6 +59,7 @@ pub fn use_ether_send_transaction(
                     }
                     Err(e) => {
                         error.set(e.to_string());
+                        ether_error.set(EtherError::from_error(&e).cloned());
                         loading.set(false);
                     }
                 };
@@ -59,5 +67,5 @@ pub fn use_ether_send_transaction(
         }
     });
 
-    (data, error, loading)
+    (data, error, loading, ether_error)
 }
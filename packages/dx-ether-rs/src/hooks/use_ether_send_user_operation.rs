@@ -0,0 +1,71 @@
+use alloy_primitives::Address;
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::user_operation_adapter::BundlerAdapter, ports::bundler_port::BundlerPort,
+    types::user_operation_types::UserOperation,
+};
+
+#[derive(Clone)]
+pub struct UseEtherSendUserOperation {
+    pub bundler_url: String,
+    // Zero nonce sequence, fees and gas limits are filled before sending, the signature must be set (a dummy one when `owner` signs);
+    pub user_operation: UserOperation,
+    // Wallet account signing the `userOpHash`, `None` sends the signature as is;
+    pub owner: Option<Address>,
+    pub activated: Option<Signal<bool>>,
+}
+
+/*
+    Returns the `userOpHash` accepted by the bundler, see `use_ether_get_user_operation_receipt`.
+*/
+pub fn use_ether_send_user_operation(
+    payload: &UseEtherSendUserOperation,
+) -> (Signal<String>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(String::new);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let payload = payload.clone();
+    let activated = payload.activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let payload = payload.clone();
+
+            spawn(async move {
+                let bundler = BundlerAdapter::new(&payload.bundler_url);
+
+                let result = async {
+                    let user_operation = bundler
+                        .prepare_user_operation(&payload.user_operation)
+                        .await?;
+
+                    let user_operation = match &payload.owner {
+                        Some(r) => bundler.sign_user_operation(&user_operation, r).await?,
+                        None => user_operation,
+                    };
+
+                    bundler.send_user_operation(&user_operation).await
+                };
+
+                match result.await {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
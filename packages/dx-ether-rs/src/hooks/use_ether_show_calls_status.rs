@@ -0,0 +1,41 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ether_adapter::EtherAdapter,
+    ports::ether::wallet_show_calls_status_port::WalletShowCallsStatusPort,
+};
+
+pub fn use_ether_show_calls_status(
+    id: &str,
+    activated: Option<Signal<bool>>,
+) -> (Signal<String>, Signal<bool>) {
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let id = id.to_string();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let id = id.clone();
+
+            spawn(async move {
+                match EtherAdapter::new().wallet_show_calls_status(&id).await {
+                    Ok(_) => {
+                        loading.set(false);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (error, loading)
+}
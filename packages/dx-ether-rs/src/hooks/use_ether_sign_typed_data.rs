@@ -0,0 +1,60 @@
+use alloy_dyn_abi::TypedData;
+use alloy_primitives::Address;
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ether_adapter::EtherAdapter,
+    ports::ether::ether_sign_typed_data_port::EtherSignTypedDataPort,
+    types::ether_typed_data_types::EtherTypedDataSignature,
+};
+
+#[derive(Clone)]
+pub struct UseEtherSignTypedData {
+    pub address: Address,
+    // Build with `EtherTypedDataSignature::typed_data(&message, domain)`;
+    pub typed_data: TypedData,
+    pub activated: Option<Signal<bool>>,
+}
+
+pub fn use_ether_sign_typed_data(
+    payload: &UseEtherSignTypedData,
+) -> (
+    Signal<EtherTypedDataSignature>,
+    Signal<String>,
+    Signal<bool>,
+) {
+    let mut data = use_signal(EtherTypedDataSignature::default);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let payload = payload.clone();
+    let activated = payload.activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let payload = payload.clone();
+
+            spawn(async move {
+                match EtherAdapter::new()
+                    .eth_sign_typed_data_v4(payload.address, &payload.typed_data)
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -1,15 +1,18 @@
 use dioxus::prelude::*;
 
 use crate::{
-    adapters::ether_adapter::EtherAdapter,
-    ports::ether::wallet_switch_ethereum_chain_port::WalletSwitchEthereumChainPort,
+    adapters::ether_adapter::EtherAdapter, types::ether_chain_types::EtherChainParameters,
 };
 
+/*
+    `chain_id` is decimal or `0x` hex, an unknown chain is added from `chain` or the chain registry.
+*/
 pub fn use_ether_switch_chain_id(
     chain_id: &str,
+    chain: Option<EtherChainParameters>,
     activated: Option<Signal<bool>>,
 ) -> (Signal<String>, Signal<bool>) {
-    let mut error = use_signal(|| String::new());
+    let mut error = use_signal(String::new);
     let mut loading = use_signal(|| false);
 
     let chain_id = chain_id.to_string();
@@ -22,10 +25,11 @@ pub fn use_ether_switch_chain_id(
             loading.set(true);
 
             let chain_id = chain_id.to_string();
+            let chain = chain.clone();
 
             spawn(async move {
                 match EtherAdapter::new()
-                    .wallet_switch_ethereum_chain(&chain_id)
+                    .switch_chain(&chain_id, chain.as_ref())
                     .await
                 {
                     Ok(_) => {
@@ -0,0 +1,55 @@
+use alloy_primitives::Address;
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ether_signature_adapter::EtherSignatureAdapter,
+    ports::ether_signature_port::EtherSignaturePort,
+};
+
+/*
+    EIP-191 message signature check, EIP-1271 and ERC-6492 wallets included.
+*/
+pub fn use_ether_verify_signature(
+    address: &Address,
+    message: &str,
+    signature: &str,
+    activated: Option<Signal<bool>>,
+) -> (Signal<bool>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(|| false);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let address = *address;
+    let message = message.to_string();
+    let signature = signature.to_string();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let message = message.clone();
+            let signature = signature.clone();
+
+            spawn(async move {
+                match EtherSignatureAdapter::new()
+                    .verify_message(&address, message.as_bytes(), &signature)
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,48 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::ether_adapter::EtherAdapter,
+    ports::ether::wallet_watch_asset_port::WalletWatchAssetPort,
+    types::ether_watch_asset_types::{EtherWatchAsset, EtherWatchAssetResult},
+};
+
+pub fn use_ether_watch_asset(
+    asset: &EtherWatchAsset,
+    activated: Option<Signal<bool>>,
+) -> (
+    Signal<Option<EtherWatchAssetResult>>,
+    Signal<String>,
+    Signal<bool>,
+) {
+    let mut data = use_signal(|| None::<EtherWatchAssetResult>);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let asset = asset.clone();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let asset = asset.clone();
+
+            spawn(async move {
+                match EtherAdapter::new().wallet_watch_asset(&asset).await {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(Some(r));
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
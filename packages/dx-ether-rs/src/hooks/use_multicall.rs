@@ -0,0 +1,52 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::multicall_adapter::MulticallAdapter, ports::multicall_port::MulticallPort,
+    types::multicall_types::MulticallCalls,
+};
+
+/*
+    Batch typed calls through Multicall3 `aggregate3`, e.g. a `Vec<MulticallCall<balanceOfCall>>`
+    gives `Vec<MulticallResult<U256>>`, a tuple of calls gives a tuple of results.
+*/
+pub fn use_multicall<T>(
+    calls: T,
+    block: Option<String>,
+    activated: Option<Signal<bool>>,
+) -> (Signal<Option<T::Returns>>, Signal<String>, Signal<bool>)
+where
+    T: MulticallCalls + Clone + 'static,
+    T::Returns: 'static,
+{
+    let mut data = use_signal(|| None::<T::Returns>);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let calls = calls.clone();
+            let block = block.clone();
+
+            spawn(async move {
+                match MulticallAdapter::new().aggregate3(&calls, block).await {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(Some(r));
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
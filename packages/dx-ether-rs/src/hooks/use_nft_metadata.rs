@@ -0,0 +1,58 @@
+use alloy_primitives::U256;
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::{
+        ether_adapter::EtherAdapter,
+        nft_metadata_adapter::{NftGateways, NftMetadataAdapter},
+    },
+    ports::nft_metadata_port::NftMetadataPort,
+    types::nft_types::NftMetadata,
+};
+
+/*
+    Resolve a `tokenURI`/`uri` result into metadata, pass `token_id` for ERC-1155 `{id}` URIs.
+*/
+pub fn use_nft_metadata(
+    uri: &str,
+    token_id: Option<U256>,
+    gateways: Option<NftGateways>,
+    activated: Option<Signal<bool>>,
+) -> (Signal<NftMetadata>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(NftMetadata::default);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let uri = uri.to_string();
+    let gateways = gateways.unwrap_or_default();
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated && !uri.is_empty() {
+            loading.set(true);
+
+            let uri = uri.clone();
+            let gateways = gateways.clone();
+
+            spawn(async move {
+                match NftMetadataAdapter::with_provider(EtherAdapter::new(), gateways)
+                    .resolve(&uri, token_id)
+                    .await
+                {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
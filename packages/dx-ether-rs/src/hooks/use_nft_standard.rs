@@ -0,0 +1,41 @@
+use alloy_primitives::Address;
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::erc165_adapter::Erc165Adapter, ports::erc165_port::Erc165Port,
+    types::nft_types::NftStandard,
+};
+
+pub fn use_nft_standard(
+    token: Address,
+    activated: Option<Signal<bool>>,
+) -> (Signal<NftStandard>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(NftStandard::default);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let activated = activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            spawn(async move {
+                match Erc165Adapter::new(token).detect_standard().await {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -0,0 +1,99 @@
+use dioxus::prelude::*;
+
+use crate::{
+    adapters::{
+        ether_adapter::EtherAdapter,
+        siwe_adapter::{SiweAdapter, format_timestamp},
+    },
+    errors::error::Error,
+    ports::{
+        ether::{
+            ether_accounts_port::EtherAccountsPort, ether_chain_id_port::EtherChainIdPort,
+            personal_sign_port::PersonalSignPort,
+        },
+        siwe_port::SiwePort,
+    },
+    types::siwe_types::{SiweMessage, SiweSession},
+};
+
+#[derive(Clone, Default)]
+pub struct UseSiweSignIn {
+    pub domain: String,
+    pub uri: String,
+    pub statement: Option<String>,
+    // Issued by the backend, checked again by `SiwePort::verify`;
+    pub nonce: String,
+    pub expiration_time: Option<String>,
+    pub resources: Vec<String>,
+    pub activated: Option<Signal<bool>>,
+}
+
+async fn sign_in(payload: &UseSiweSignIn) -> Result<SiweSession, Error> {
+    let ether = EtherAdapter::new();
+
+    let address = ether
+        .eth_accounts()
+        .await?
+        .into_iter()
+        .next()
+        .ok_or("[siwe]: no connected account")?;
+
+    let chain_id = ether.eth_chain_id().await?.parse::<u64>()?;
+    let issued_at = format_timestamp(ether.date_now().await?);
+
+    let mut message = SiweMessage::new(&payload.domain, &address, &payload.uri, chain_id)
+        .nonce(&payload.nonce)
+        .issued_at(&issued_at);
+
+    message.statement = payload.statement.clone();
+    message.expiration_time = payload.expiration_time.clone();
+    message.resources = payload.resources.clone();
+
+    let text = SiweAdapter::new().build_message(&message)?;
+    let signature = ether.personal_sign(&text.as_str().into(), &address).await?;
+
+    Ok(SiweSession {
+        message: Some(message),
+        text,
+        signature: signature.signature,
+    })
+}
+
+/*
+    EIP-4361 sign-in with the connected account and chain.
+*/
+pub fn use_siwe_sign_in(
+    payload: &UseSiweSignIn,
+) -> (Signal<SiweSession>, Signal<String>, Signal<bool>) {
+    let mut data = use_signal(SiweSession::default);
+    let mut error = use_signal(String::new);
+    let mut loading = use_signal(|| false);
+
+    let payload = payload.clone();
+    let activated = payload.activated.unwrap_or(Signal::new(false));
+
+    use_effect(move || {
+        let activated = activated();
+
+        if activated {
+            loading.set(true);
+
+            let payload = payload.clone();
+
+            spawn(async move {
+                match sign_in(&payload).await {
+                    Ok(r) => {
+                        loading.set(false);
+                        data.set(r);
+                    }
+                    Err(e) => {
+                        error.set(e.to_string());
+                        loading.set(false);
+                    }
+                };
+            });
+        }
+    });
+
+    (data, error, loading)
+}
@@ -1,5 +1,10 @@
+pub mod abi;
 pub mod adapters;
 pub mod constants;
 pub mod errors;
 pub mod hooks;
 pub mod ports;
+pub mod types;
+
+#[cfg(test)]
+mod test_utils;
@@ -0,0 +1,29 @@
+use crate::types::user_operation_types::{
+    EtherUserOperationGas, EtherUserOperationReceipt, UserOperation,
+};
+
+pub trait BundlerPort {
+    type Error;
+
+    fn supported_entry_points(&self) -> impl Future<Output = Result<Vec<String>, Self::Error>>;
+
+    fn estimate_user_operation_gas(
+        &self,
+        user_operation: &UserOperation,
+    ) -> impl Future<Output = Result<EtherUserOperationGas, Self::Error>>;
+
+    fn send_user_operation(
+        &self,
+        user_operation: &UserOperation,
+    ) -> impl Future<Output = Result<String, Self::Error>>;
+
+    fn get_user_operation_receipt(
+        &self,
+        hash: &str,
+    ) -> impl Future<Output = Result<Option<EtherUserOperationReceipt>, Self::Error>>;
+
+    fn wait_user_operation_receipt(
+        &self,
+        hash: &str,
+    ) -> impl Future<Output = Result<EtherUserOperationReceipt, Self::Error>>;
+}
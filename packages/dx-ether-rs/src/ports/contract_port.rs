@@ -0,0 +1,19 @@
+use alloy_sol_types::SolCall;
+
+use crate::types::contract_types::ContractTransactionOptions;
+
+pub trait ContractPort<I> {
+    type Error;
+
+    fn read<C: SolCall>(
+        &self,
+        call: C,
+        block: Option<String>,
+    ) -> impl Future<Output = Result<C::Return, Self::Error>>;
+
+    fn write<C: SolCall>(
+        &self,
+        call: C,
+        options: &ContractTransactionOptions,
+    ) -> impl Future<Output = Result<String, Self::Error>>;
+}
@@ -0,0 +1,23 @@
+use alloy_primitives::Address;
+
+pub trait EnsPort {
+    type Error;
+
+    fn resolve_name(
+        &self,
+        name: &str,
+    ) -> impl Future<Output = Result<Option<Address>, Self::Error>>;
+
+    fn lookup_address(
+        &self,
+        address: &Address,
+    ) -> impl Future<Output = Result<Option<String>, Self::Error>>;
+
+    fn text(
+        &self,
+        name: &str,
+        key: &str,
+    ) -> impl Future<Output = Result<Option<String>, Self::Error>>;
+
+    fn avatar(&self, name: &str) -> impl Future<Output = Result<Option<String>, Self::Error>>;
+}
@@ -0,0 +1,52 @@
+use alloy_primitives::{Address, U256};
+
+pub trait Erc1155Port {
+    type Error;
+
+    fn balance_of(
+        &self,
+        owner: Address,
+        id: U256,
+    ) -> impl Future<Output = Result<U256, Self::Error>>;
+
+    fn balance_of_batch(
+        &self,
+        owners: &[Address],
+        ids: &[U256],
+    ) -> impl Future<Output = Result<Vec<U256>, Self::Error>>;
+
+    fn uri(&self, id: U256) -> impl Future<Output = Result<String, Self::Error>>;
+
+    fn is_approved_for_all(
+        &self,
+        owner: Address,
+        operator: Address,
+    ) -> impl Future<Output = Result<bool, Self::Error>>;
+
+    fn set_approval_for_all(
+        &self,
+        from: Address,
+        operator: Address,
+        approved: bool,
+    ) -> impl Future<Output = Result<String, Self::Error>>;
+
+    fn safe_transfer_from(
+        &self,
+        from: Address,
+        owner: Address,
+        to: Address,
+        id: U256,
+        amount: U256,
+        data: Vec<u8>,
+    ) -> impl Future<Output = Result<String, Self::Error>>;
+
+    fn safe_batch_transfer_from(
+        &self,
+        from: Address,
+        owner: Address,
+        to: Address,
+        ids: Vec<U256>,
+        amounts: Vec<U256>,
+        data: Vec<u8>,
+    ) -> impl Future<Output = Result<String, Self::Error>>;
+}
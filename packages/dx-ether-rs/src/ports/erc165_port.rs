@@ -0,0 +1,12 @@
+use crate::types::nft_types::NftStandard;
+
+pub trait Erc165Port {
+    type Error;
+
+    fn supports_interface(
+        &self,
+        interface_id: [u8; 4],
+    ) -> impl Future<Output = Result<bool, Self::Error>>;
+
+    fn detect_standard(&self) -> impl Future<Output = Result<NftStandard, Self::Error>>;
+}
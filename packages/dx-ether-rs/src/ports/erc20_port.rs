@@ -0,0 +1,45 @@
+use alloy_primitives::{Address, U256};
+
+use crate::types::erc20_types::{Erc20Amount, Erc20Metadata};
+
+pub trait Erc20Port {
+    type Error;
+
+    fn metadata(&self) -> impl Future<Output = Result<Erc20Metadata, Self::Error>>;
+
+    fn symbol(&self) -> impl Future<Output = Result<String, Self::Error>>;
+
+    fn decimals(&self) -> impl Future<Output = Result<u8, Self::Error>>;
+
+    fn total_supply(&self) -> impl Future<Output = Result<Erc20Amount, Self::Error>>;
+
+    fn balance_of(&self, owner: Address) -> impl Future<Output = Result<Erc20Amount, Self::Error>>;
+
+    fn allowance(
+        &self,
+        owner: Address,
+        spender: Address,
+    ) -> impl Future<Output = Result<Erc20Amount, Self::Error>>;
+
+    fn transfer(
+        &self,
+        from: Address,
+        to: Address,
+        amount: U256,
+    ) -> impl Future<Output = Result<String, Self::Error>>;
+
+    fn approve(
+        &self,
+        from: Address,
+        spender: Address,
+        amount: U256,
+    ) -> impl Future<Output = Result<String, Self::Error>>;
+
+    fn transfer_from(
+        &self,
+        from: Address,
+        owner: Address,
+        to: Address,
+        amount: U256,
+    ) -> impl Future<Output = Result<String, Self::Error>>;
+}
@@ -0,0 +1,33 @@
+use alloy_primitives::{Address, U256};
+
+pub trait Erc721Port {
+    type Error;
+
+    fn owner_of(&self, token_id: U256) -> impl Future<Output = Result<Address, Self::Error>>;
+
+    fn balance_of(&self, owner: Address) -> impl Future<Output = Result<U256, Self::Error>>;
+
+    fn token_uri(&self, token_id: U256) -> impl Future<Output = Result<String, Self::Error>>;
+
+    fn is_approved_for_all(
+        &self,
+        owner: Address,
+        operator: Address,
+    ) -> impl Future<Output = Result<bool, Self::Error>>;
+
+    fn set_approval_for_all(
+        &self,
+        from: Address,
+        operator: Address,
+        approved: bool,
+    ) -> impl Future<Output = Result<String, Self::Error>>;
+
+    fn safe_transfer_from(
+        &self,
+        from: Address,
+        owner: Address,
+        to: Address,
+        token_id: U256,
+        data: Vec<u8>,
+    ) -> impl Future<Output = Result<String, Self::Error>>;
+}
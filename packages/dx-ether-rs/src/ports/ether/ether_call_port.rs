@@ -1,10 +1,12 @@
+use alloy_primitives::Address;
+
 pub trait EtherCallPort: Send + Sync {
     type Output;
     type Error;
 
     fn eth_call(
         &self,
-        address: &str,
+        address: Address,
         calldata: &str,
         block: Option<String>,
     ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
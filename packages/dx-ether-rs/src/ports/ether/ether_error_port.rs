@@ -1,4 +1,6 @@
 pub trait EtherErrorPort {
     fn message(&self) -> String;
     fn stack(&self) -> String;
+    fn code(&self) -> Option<i64>;
+    fn data(&self) -> Option<serde_json::Value>;
 }
@@ -0,0 +1,14 @@
+use alloy_primitives::Address;
+
+pub trait EtherEstimateGasPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn eth_estimate_gas(
+        &self,
+        from: Option<Address>,
+        to: Address,
+        value: Option<String>,
+        calldata: Option<String>,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
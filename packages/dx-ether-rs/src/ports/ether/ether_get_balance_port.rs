@@ -1,10 +1,12 @@
+use alloy_primitives::Address;
+
 pub trait EtherGetBalancerPort: Send + Sync {
     type Output;
     type Error;
 
     fn eth_get_balance(
         &self,
-        address: &str,
+        address: Address,
         block: Option<String>,
     ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
 }
@@ -0,0 +1,10 @@
+pub trait EtherGetBlockByHashPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn eth_get_block_by_hash(
+        &self,
+        block_hash: &str,
+        full_transactions: bool,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -0,0 +1,10 @@
+pub trait EtherGetBlockByNumberPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn eth_get_block_by_number(
+        &self,
+        block: &str,
+        full_transactions: bool,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -0,0 +1,9 @@
+pub trait EtherGetBlockReceiptsPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn eth_get_block_receipts(
+        &self,
+        block: &str,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -0,0 +1,9 @@
+pub trait EtherGetFilterChangesPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn eth_get_filter_changes(
+        &self,
+        filter_id: &str,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
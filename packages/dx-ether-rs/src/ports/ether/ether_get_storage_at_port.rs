@@ -0,0 +1,13 @@
+use alloy_primitives::Address;
+
+pub trait EtherGetStorageAtPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn eth_get_storage_at(
+        &self,
+        address: Address,
+        slot: &str,
+        block: Option<String>,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -0,0 +1,10 @@
+pub trait EtherGetTransactionByBlockNumberAndIndexPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn eth_get_transaction_by_block_number_and_index(
+        &self,
+        block: &str,
+        index: &str,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
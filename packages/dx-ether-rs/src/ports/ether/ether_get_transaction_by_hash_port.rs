@@ -0,0 +1,9 @@
+pub trait EtherGetTransactionByHashPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn eth_get_transaction_by_hash(
+        &self,
+        transaction_hash: &str,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -0,0 +1,12 @@
+use alloy_primitives::Address;
+
+pub trait EtherGetTransactionCountPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn eth_get_transaction_count(
+        &self,
+        address: Address,
+        block: Option<String>,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -0,0 +1,8 @@
+pub trait EtherMaxPriorityFeePerGasPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn eth_max_priority_fee_per_gas(
+        &self,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -0,0 +1,11 @@
+use crate::types::ether_log_types::EtherLogFilter;
+
+pub trait EtherNewFilterPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn eth_new_filter(
+        &self,
+        filter: &EtherLogFilter,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -1,11 +1,13 @@
+use alloy_primitives::Address;
+
 pub trait EtherSendTransactionPort {
     type Output;
     type Error;
 
     fn eth_send_transaction(
         &self,
-        from: &str,
-        to: &str,
+        from: Address,
+        to: Address,
         value: Option<String>,
         calldata: Option<String>,
         gas: Option<String>,
@@ -0,0 +1,14 @@
+use alloy_primitives::Address;
+
+use alloy_dyn_abi::TypedData;
+
+pub trait EtherSignTypedDataPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn eth_sign_typed_data_v4(
+        &self,
+        address: Address,
+        typed_data: &TypedData,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
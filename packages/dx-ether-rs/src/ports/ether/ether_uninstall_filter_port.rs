@@ -0,0 +1,9 @@
+pub trait EtherUninstallFilterPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn eth_uninstall_filter(
+        &self,
+        filter_id: &str,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
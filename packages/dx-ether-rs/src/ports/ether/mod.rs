@@ -3,10 +3,34 @@ pub mod ether_block_number_port;
 pub mod ether_call_port;
 pub mod ether_chain_id_port;
 pub mod ether_error_port;
+pub mod ether_estimate_gas_port;
 pub mod ether_gas_price_port;
 pub mod ether_get_balance_port;
+pub mod ether_get_block_by_hash_port;
+pub mod ether_get_block_by_number_port;
+pub mod ether_get_block_receipts_port;
+pub mod ether_get_code_port;
+pub mod ether_get_filter_changes_port;
+pub mod ether_get_logs_port;
+pub mod ether_get_storage_at_port;
+pub mod ether_get_transaction_by_block_number_and_index_port;
+pub mod ether_get_transaction_by_hash_port;
+pub mod ether_get_transaction_count_port;
 pub mod ether_get_transaction_receipt_port;
+pub mod ether_max_priority_fee_per_gas_port;
+pub mod ether_new_filter_port;
 pub mod ether_request_accounts_port;
 pub mod ether_send_transaction_port;
+pub mod ether_sign_typed_data_port;
+pub mod ether_uninstall_filter_port;
 pub mod personal_sign_port;
+pub mod wallet_add_ethereum_chain_port;
+pub mod wallet_get_calls_status_port;
+pub mod wallet_get_capabilities_port;
+pub mod wallet_get_permissions_port;
+pub mod wallet_request_permissions_port;
+pub mod wallet_revoke_permissions_port;
+pub mod wallet_send_calls_port;
+pub mod wallet_show_calls_status_port;
 pub mod wallet_switch_ethereum_chain_port;
+pub mod wallet_watch_asset_port;
@@ -1,10 +1,14 @@
+use alloy_primitives::Address;
+
+use crate::types::personal_sign_types::EtherPersonalSignMessage;
+
 pub trait PersonalSignPort: Send + Sync {
     type Output;
     type Error;
 
     fn personal_sign(
         &self,
-        message: &str,
-        address: &str,
+        message: &EtherPersonalSignMessage,
+        address: &Address,
     ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
 }
@@ -0,0 +1,11 @@
+use crate::types::ether_chain_types::EtherChainParameters;
+
+pub trait WalletAddEthereumChainPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn wallet_add_ethereum_chain(
+        &self,
+        chain: &EtherChainParameters,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -0,0 +1,9 @@
+pub trait WalletGetCallsStatusPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn wallet_get_calls_status(
+        &self,
+        id: &str,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -0,0 +1,12 @@
+use alloy_primitives::Address;
+
+pub trait WalletGetCapabilitiesPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn wallet_get_capabilities(
+        &self,
+        address: Address,
+        chain_ids: &[&str],
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
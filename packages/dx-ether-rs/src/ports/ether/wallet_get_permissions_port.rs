@@ -0,0 +1,6 @@
+pub trait WalletGetPermissionsPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn wallet_get_permissions(&self) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -0,0 +1,9 @@
+pub trait WalletRequestPermissionsPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn wallet_request_permissions(
+        &self,
+        capabilities: &[&str],
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
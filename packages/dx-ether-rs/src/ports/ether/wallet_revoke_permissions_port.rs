@@ -0,0 +1,9 @@
+pub trait WalletRevokePermissionsPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn wallet_revoke_permissions(
+        &self,
+        capabilities: &[&str],
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -0,0 +1,11 @@
+use crate::types::ether_calls_types::EtherSendCalls;
+
+pub trait WalletSendCallsPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn wallet_send_calls(
+        &self,
+        calls: &EtherSendCalls,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -0,0 +1,11 @@
+use crate::types::ether_watch_asset_types::EtherWatchAsset;
+
+pub trait WalletWatchAssetPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn wallet_watch_asset(
+        &self,
+        asset: &EtherWatchAsset,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -0,0 +1,9 @@
+use alloy_primitives::Address;
+
+pub trait EtherAddressPort {
+    type Error;
+
+    fn parse_address(&self, address: &str) -> Result<Address, Self::Error>;
+
+    fn checksum_address(&self, address: &Address) -> String;
+}
@@ -1,7 +1,7 @@
 pub trait EtherHashPort {
     type Error;
 
-    fn encode_to_string(&self, bytes: &Vec<u8>) -> Result<String, Self::Error>;
+    fn encode_to_string(&self, bytes: &[u8]) -> Result<String, Self::Error>;
 
     fn encode_to_number(&self, hash: &str) -> Result<String, Self::Error>;
 
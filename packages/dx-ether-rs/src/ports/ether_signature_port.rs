@@ -0,0 +1,31 @@
+use alloy_primitives::{Address, B256};
+
+use crate::types::ether_signature_types::EtherSignatureParts;
+
+pub trait EtherSignaturePort {
+    type Error;
+
+    fn split_signature(&self, signature: &str) -> Result<EtherSignatureParts, Self::Error>;
+
+    fn join_signature(&self, parts: &EtherSignatureParts) -> Result<String, Self::Error>;
+
+    fn compact_signature(&self, signature: &str) -> Result<String, Self::Error>;
+
+    fn recover_message(&self, message: &[u8], signature: &str) -> Result<Address, Self::Error>;
+
+    fn recover_hash(&self, hash: &B256, signature: &str) -> Result<Address, Self::Error>;
+
+    fn verify_message(
+        &self,
+        address: &Address,
+        message: &[u8],
+        signature: &str,
+    ) -> impl Future<Output = Result<bool, Self::Error>>;
+
+    fn verify_hash(
+        &self,
+        address: &Address,
+        hash: &B256,
+        signature: &str,
+    ) -> impl Future<Output = Result<bool, Self::Error>>;
+}
@@ -0,0 +1,18 @@
+use alloy_primitives::U256;
+
+use crate::types::ether_units_types::EtherUnitsFormat;
+
+pub trait EtherUnitsPort {
+    type Error;
+
+    fn parse_units(&self, value: &str, decimals: u8) -> Result<U256, Self::Error>;
+
+    fn format_units(&self, value: U256, decimals: u8) -> Result<String, Self::Error>;
+
+    fn format_with(
+        &self,
+        value: U256,
+        decimals: u8,
+        options: &EtherUnitsFormat,
+    ) -> Result<String, Self::Error>;
+}
@@ -0,0 +1,6 @@
+pub trait HttpGetPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn http_get(&self, url: &str) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -0,0 +1,10 @@
+pub trait HttpPostPort: Send + Sync {
+    type Output;
+    type Error;
+
+    fn http_post(
+        &self,
+        url: &str,
+        body: &str,
+    ) -> impl Future<Output = Result<Self::Output, Self::Error>>;
+}
@@ -1,2 +1,17 @@
+pub mod bundler_port;
+pub mod contract_port;
+pub mod ens_port;
+pub mod erc1155_port;
+pub mod erc165_port;
+pub mod erc20_port;
+pub mod erc721_port;
 pub mod ether;
+pub mod ether_address_port;
 pub mod ether_hash_port;
+pub mod ether_signature_port;
+pub mod ether_units_port;
+pub mod http_get_port;
+pub mod http_post_port;
+pub mod multicall_port;
+pub mod nft_metadata_port;
+pub mod siwe_port;
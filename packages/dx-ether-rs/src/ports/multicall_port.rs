@@ -0,0 +1,26 @@
+use alloy_primitives::{Address, U256};
+
+use crate::types::multicall_types::MulticallCalls;
+
+pub trait MulticallPort {
+    type Error;
+
+    fn aggregate3<T: MulticallCalls>(
+        &self,
+        calls: &T,
+        block: Option<String>,
+    ) -> impl Future<Output = Result<T::Returns, Self::Error>>;
+
+    fn try_aggregate<T: MulticallCalls>(
+        &self,
+        require_success: bool,
+        calls: &T,
+        block: Option<String>,
+    ) -> impl Future<Output = Result<T::Returns, Self::Error>>;
+
+    fn get_eth_balance(
+        &self,
+        address: Address,
+        block: Option<String>,
+    ) -> impl Future<Output = Result<U256, Self::Error>>;
+}
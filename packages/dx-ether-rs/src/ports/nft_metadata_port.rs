@@ -0,0 +1,13 @@
+use alloy_primitives::U256;
+
+use crate::types::nft_types::NftMetadata;
+
+pub trait NftMetadataPort {
+    type Error;
+
+    fn resolve(
+        &self,
+        uri: &str,
+        token_id: Option<U256>,
+    ) -> impl Future<Output = Result<NftMetadata, Self::Error>>;
+}
@@ -0,0 +1,29 @@
+use crate::types::siwe_types::{SiweMessage, SiweVerifyOptions};
+
+pub trait SiwePort {
+    type Error;
+
+    fn build_message(&self, message: &SiweMessage) -> Result<String, Self::Error>;
+
+    fn parse_message(&self, message: &str) -> Result<SiweMessage, Self::Error>;
+
+    fn validate(
+        &self,
+        message: &SiweMessage,
+        options: &SiweVerifyOptions,
+    ) -> Result<(), Self::Error>;
+
+    fn verify_offline(
+        &self,
+        message: &str,
+        signature: &str,
+        options: &SiweVerifyOptions,
+    ) -> Result<SiweMessage, Self::Error>;
+
+    fn verify(
+        &self,
+        message: &str,
+        signature: &str,
+        options: &SiweVerifyOptions,
+    ) -> impl Future<Output = Result<SiweMessage, Self::Error>>;
+}
@@ -0,0 +1,18 @@
+use std::{
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+/*
+    Adapters under test only await stub providers, which are always ready.
+*/
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(r) = future.as_mut().poll(&mut context) {
+            return r;
+        }
+    }
+}
@@ -0,0 +1,20 @@
+/*
+    EIP-3668 CCIP-Read for `eth_call`: gateways are only queried when they match `allowed_urls`
+    (any `https://` URL when unset) and at most `max_lookups` lookups are followed per call.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct CcipReadOptions {
+    pub enabled: bool,
+    pub max_lookups: u8,
+    pub allowed_urls: Option<Vec<String>>,
+}
+
+impl Default for CcipReadOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_lookups: 4,
+            allowed_urls: None,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use alloy_primitives::Address;
+
+#[derive(Clone, Default)]
+pub struct ContractTransactionOptions {
+    pub from: Address,
+    pub value: Option<String>,
+    pub gas: Option<String>,
+    pub gas_price: Option<String>,
+}
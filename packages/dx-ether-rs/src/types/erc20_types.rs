@@ -0,0 +1,50 @@
+use alloy_primitives::U256;
+use std::fmt;
+
+use crate::{
+    adapters::ether_units_adapter::EtherUnitsAdapter, errors::error::Error,
+    ports::ether_units_port::EtherUnitsPort, types::ether_units_types::EtherUnitsFormat,
+};
+
+#[derive(Default, Clone, PartialEq, Debug)]
+pub struct Erc20Metadata {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+/*
+    Raw token amount together with the token decimals.
+*/
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub struct Erc20Amount {
+    pub value: U256,
+    pub decimals: u8,
+}
+
+impl Erc20Amount {
+    pub fn new(value: U256, decimals: u8) -> Self {
+        Self { value, decimals }
+    }
+
+    pub fn parse(value: &str, decimals: u8) -> Result<Self, Error> {
+        Ok(Self::new(
+            EtherUnitsAdapter::new().parse_units(value, decimals)?,
+            decimals,
+        ))
+    }
+
+    pub fn format(&self, options: &EtherUnitsFormat) -> Result<String, Error> {
+        EtherUnitsAdapter::new().format_with(self.value, self.decimals, options)
+    }
+}
+
+impl fmt::Display for Erc20Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = EtherUnitsAdapter::new()
+            .format_units(self.value, self.decimals)
+            .map_err(|_| fmt::Error)?;
+
+        write!(f, "{}", value)
+    }
+}
@@ -0,0 +1,207 @@
+use alloy_primitives::{Address, U256, hex};
+use alloy_sol_types::SolCall;
+use serde::Deserialize;
+
+use crate::adapters::ether_adapter::EtherLog;
+
+#[derive(Default, Clone, PartialEq, Debug, serde::Serialize)]
+pub struct EtherCall {
+    pub to: Address,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    // `0x` hex quantity in wei;
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+impl EtherCall {
+    pub fn new(to: Address) -> Self {
+        Self {
+            to,
+            ..Default::default()
+        }
+    }
+
+    /*
+        Call of an alloy `sol!` function, e.g. `ERC20::approveCall`.
+    */
+    pub fn contract<C: SolCall>(to: Address, call: &C) -> Self {
+        Self::new(to).data(&hex::encode_prefixed(call.abi_encode()))
+    }
+
+    pub fn data(mut self, data: &str) -> Self {
+        self.data = Some(data.to_string());
+        self
+    }
+
+    pub fn value(mut self, value: U256) -> Self {
+        self.value = Some(format!("0x{:x}", value));
+        self
+    }
+}
+
+/*
+    EIP-5792 `wallet_sendCalls` request, `chain_id` accepts decimal or `0x` hex.
+*/
+#[derive(Default, Clone, PartialEq, Debug)]
+pub struct EtherSendCalls {
+    pub from: Address,
+    pub chain_id: String,
+    pub calls: Vec<EtherCall>,
+    pub atomic_required: bool,
+    // ERC-7677 paymaster web service;
+    pub paymaster_url: Option<String>,
+}
+
+impl EtherSendCalls {
+    pub fn new(from: Address, chain_id: &str) -> Self {
+        Self {
+            from,
+            chain_id: chain_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn call(mut self, call: EtherCall) -> Self {
+        self.calls.push(call);
+        self
+    }
+
+    pub fn atomic_required(mut self, atomic_required: bool) -> Self {
+        self.atomic_required = atomic_required;
+        self
+    }
+
+    pub fn paymaster_url(mut self, url: &str) -> Self {
+        self.paymaster_url = Some(url.to_string());
+        self
+    }
+}
+
+/*
+    Capabilities of one chain, `atomic` (v2) and `atomicBatch` (v1) are both understood.
+*/
+#[derive(Default, Clone, PartialEq, Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EtherCapabilities {
+    #[serde(default)]
+    pub atomic: Option<EtherAtomicCapability>,
+    #[serde(default)]
+    pub atomic_batch: Option<EtherSupportedCapability>,
+    #[serde(default)]
+    pub paymaster_service: Option<EtherSupportedCapability>,
+}
+
+#[derive(Default, Clone, PartialEq, Debug, serde::Deserialize)]
+pub struct EtherAtomicCapability {
+    // `supported`, `ready` (after an upgrade prompt) or `unsupported`;
+    pub status: String,
+}
+
+#[derive(Default, Clone, PartialEq, Debug, serde::Deserialize)]
+pub struct EtherSupportedCapability {
+    #[serde(default)]
+    pub supported: bool,
+}
+
+impl EtherCapabilities {
+    pub fn supports_atomic(&self) -> bool {
+        self.atomic
+            .as_ref()
+            .is_some_and(|r| r.status == "supported" || r.status == "ready")
+            || self.atomic_batch.as_ref().is_some_and(|r| r.supported)
+    }
+
+    pub fn supports_paymaster(&self) -> bool {
+        self.paymaster_service.as_ref().is_some_and(|r| r.supported)
+    }
+}
+
+#[derive(Default, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EtherCallsReceipt {
+    #[serde(default)]
+    pub logs: Vec<EtherLog>,
+    pub status: String,
+    pub block_hash: String,
+    pub block_number: String,
+    pub gas_used: String,
+    pub transaction_hash: String,
+}
+
+/*
+    `wallet_getCallsStatus` result, v1 `PENDING`/`CONFIRMED` strings are mapped to 100/200.
+*/
+#[derive(Default, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EtherCallsStatus {
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub chain_id: String,
+    #[serde(deserialize_with = "deserialize_calls_status")]
+    pub status: u16,
+    #[serde(default)]
+    pub atomic: bool,
+    #[serde(default)]
+    pub receipts: Vec<EtherCallsReceipt>,
+}
+
+fn deserialize_calls_status<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<u16, D::Error> {
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Number(r) => r
+            .as_u64()
+            .and_then(|r| u16::try_from(r).ok())
+            .ok_or_else(|| serde::de::Error::custom("invalid calls status")),
+        serde_json::Value::String(r) if r == "PENDING" => Ok(100),
+        serde_json::Value::String(r) if r == "CONFIRMED" => Ok(200),
+        r => Err(serde::de::Error::custom(format!(
+            "invalid calls status {}",
+            r
+        ))),
+    }
+}
+
+impl EtherCallsStatus {
+    pub fn is_pending(&self) -> bool {
+        self.status < 200
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.status == 200
+    }
+
+    // 400 not included onchain, 500 reverted, 600 partially reverted;
+    pub fn is_failed(&self) -> bool {
+        self.status >= 400
+    }
+}
+
+/*
+    Batch id for `wallet_getCallsStatus`, or the transaction hashes of the sequential fallback.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub enum EtherSentCalls {
+    Batch(String),
+    Sequential(Vec<String>),
+}
+
+/*
+    `wallet_sendCalls` returns a bare id in EIP-5792 v1 and `{ id }` since v2.
+*/
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+pub enum EtherSendCallsResult {
+    Id(String),
+    Object { id: String },
+}
+
+impl Default for EtherSendCallsResult {
+    fn default() -> Self {
+        Self::Id(String::new())
+    }
+}
@@ -0,0 +1,22 @@
+/*
+    EIP-3085 chain metadata, `chain_id` accepts decimal or `0x` hex.
+*/
+#[derive(Default, Clone, PartialEq, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EtherChainParameters {
+    pub chain_id: String,
+    pub chain_name: String,
+    pub native_currency: EtherNativeCurrency,
+    pub rpc_urls: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub block_explorer_urls: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub icon_urls: Vec<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Debug, serde::Serialize)]
+pub struct EtherNativeCurrency {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+}
@@ -0,0 +1,96 @@
+use alloy_primitives::{Address, B256, U256};
+use alloy_sol_types::SolEvent;
+
+/*
+    `eth_getLogs` and `eth_newFilter` topic filter, a position matches any, one or one of.
+*/
+#[derive(Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum EtherTopic {
+    #[default]
+    Any,
+    One(String),
+    OneOf(Vec<String>),
+}
+
+impl EtherTopic {
+    pub fn word(word: B256) -> Self {
+        Self::One(word.to_string())
+    }
+
+    pub fn words(words: impl IntoIterator<Item = B256>) -> Self {
+        Self::OneOf(words.into_iter().map(|i| i.to_string()).collect())
+    }
+
+    /*
+        Indexed `address` parameters are stored left-padded to 32 bytes.
+    */
+    pub fn address(address: &Address) -> Self {
+        Self::word(address.into_word())
+    }
+
+    pub fn uint(value: U256) -> Self {
+        Self::word(B256::from(value))
+    }
+}
+
+#[derive(Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EtherLogFilter {
+    #[serde(rename = "fromBlock", skip_serializing_if = "Option::is_none")]
+    pub from_block: Option<String>,
+    #[serde(rename = "toBlock", skip_serializing_if = "Option::is_none")]
+    pub to_block: Option<String>,
+    #[serde(rename = "blockHash", skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub address: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub topics: Vec<EtherTopic>,
+}
+
+impl EtherLogFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn address(mut self, address: &Address) -> Self {
+        self.address.push(address.to_checksum(None));
+        self
+    }
+
+    pub fn from_block(mut self, block: &str) -> Self {
+        self.from_block = Some(block.to_string());
+        self
+    }
+
+    pub fn to_block(mut self, block: &str) -> Self {
+        self.to_block = Some(block.to_string());
+        self
+    }
+
+    /*
+        Block hash and block range are mutually exclusive, so the range is dropped.
+    */
+    pub fn block_hash(mut self, hash: &str) -> Self {
+        self.block_hash = Some(hash.to_string());
+        self.from_block = None;
+        self.to_block = None;
+        self
+    }
+
+    /*
+        Positions left unset before `position` are filled with wildcards.
+    */
+    pub fn topic(mut self, position: usize, topic: EtherTopic) -> Self {
+        if self.topics.len() <= position {
+            self.topics.resize(position + 1, EtherTopic::Any);
+        }
+
+        self.topics[position] = topic;
+        self
+    }
+
+    pub fn event<E: SolEvent>(self) -> Self {
+        self.topic(0, EtherTopic::word(E::SIGNATURE_HASH))
+    }
+}
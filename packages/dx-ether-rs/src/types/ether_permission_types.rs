@@ -0,0 +1,53 @@
+use alloy_primitives::Address;
+
+/*
+    EIP-2255 permission, `eth_accounts` usually carries a `restrictReturnedAccounts` caveat.
+*/
+#[derive(Default, Clone, PartialEq, Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EtherPermission {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default)]
+    pub invoker: String,
+    pub parent_capability: String,
+    #[serde(default)]
+    pub caveats: Vec<EtherPermissionCaveat>,
+    // Unix time in milliseconds;
+    #[serde(default)]
+    pub date: Option<u64>,
+}
+
+#[derive(Default, Clone, PartialEq, Debug, serde::Deserialize)]
+pub struct EtherPermissionCaveat {
+    #[serde(rename = "type")]
+    pub caveat_type: String,
+    #[serde(default)]
+    pub value: serde_json::Value,
+}
+
+impl EtherPermission {
+    /*
+        Accounts of a `restrictReturnedAccounts` caveat, invalid entries are skipped.
+    */
+    pub fn accounts(&self) -> Vec<Address> {
+        self.caveats
+            .iter()
+            .filter(|r| r.caveat_type == "restrictReturnedAccounts")
+            .filter_map(|r| r.value.as_array())
+            .flatten()
+            .filter_map(|r| r.as_str()?.parse::<Address>().ok())
+            .collect()
+    }
+}
+
+/*
+    `wallet_requestPermissions` payload, one empty object per requested capability.
+*/
+pub fn encode_permissions(capabilities: &[&str]) -> serde_json::Value {
+    capabilities
+        .iter()
+        .map(|r| (r.to_string(), serde_json::json!({})))
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
@@ -0,0 +1,11 @@
+use alloy_primitives::B256;
+
+/*
+    Signature split into scalars, `v` is always 27 or 28.
+*/
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub struct EtherSignatureParts {
+    pub r: B256,
+    pub s: B256,
+    pub v: u8,
+}
@@ -0,0 +1,25 @@
+use alloy_dyn_abi::TypedData;
+use alloy_primitives::B256;
+use alloy_sol_types::{Eip712Domain, SolStruct};
+
+/*
+    EIP-712 signature together with the signing hash computed locally from the same payload,
+    the signature is checked to come from the requested account.
+*/
+#[derive(Default, Clone, PartialEq, Debug)]
+pub struct EtherTypedDataSignature {
+    pub signature: String,
+    pub signing_hash: B256,
+}
+
+impl EtherTypedDataSignature {
+    /*
+        Typed data for an alloy `sol!` struct deriving `serde::Serialize`.
+    */
+    pub fn typed_data<S: SolStruct + serde::Serialize>(
+        message: &S,
+        domain: Eip712Domain,
+    ) -> TypedData {
+        TypedData::from_struct(message, Some(domain))
+    }
+}
@@ -0,0 +1,12 @@
+/*
+    Display options, always `.` as decimal point and `,` as thousands separator.
+*/
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct EtherUnitsFormat {
+    // Max fraction digits, rounded half up; exact when unset, 1 with `compact`;
+    pub precision: Option<u8>,
+    // 1234567.5 -> 1,234,567.5;
+    pub grouping: bool,
+    // 1234567.5 -> 1.2M;
+    pub compact: bool,
+}
@@ -0,0 +1,43 @@
+use alloy_primitives::{Address, U256};
+
+/*
+    EIP-747 asset, ERC-20 `symbol` and `decimals` are read from the token when unset.
+    NFTs are only accepted by some wallets.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub enum EtherWatchAsset {
+    Erc20 {
+        address: Address,
+        symbol: Option<String>,
+        decimals: Option<u8>,
+        image: Option<String>,
+    },
+    Erc721 {
+        address: Address,
+        token_id: U256,
+    },
+    Erc1155 {
+        address: Address,
+        token_id: U256,
+    },
+}
+
+impl EtherWatchAsset {
+    pub fn erc20(address: Address) -> Self {
+        Self::Erc20 {
+            address,
+            symbol: None,
+            decimals: None,
+            image: None,
+        }
+    }
+}
+
+/*
+    EIP-747 has no "already added" outcome, wallets resolve `true` for tracked tokens too.
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EtherWatchAssetResult {
+    Added,
+    Rejected,
+}
@@ -0,0 +1,16 @@
+pub mod ccip_read_types;
+pub mod contract_types;
+pub mod erc20_types;
+pub mod ether_calls_types;
+pub mod ether_chain_types;
+pub mod ether_log_types;
+pub mod ether_permission_types;
+pub mod ether_signature_types;
+pub mod ether_typed_data_types;
+pub mod ether_units_types;
+pub mod ether_watch_asset_types;
+pub mod multicall_types;
+pub mod nft_types;
+pub mod personal_sign_types;
+pub mod siwe_types;
+pub mod user_operation_types;
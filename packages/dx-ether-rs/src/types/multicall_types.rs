@@ -0,0 +1,166 @@
+use alloy_primitives::{Address, Bytes, hex};
+use alloy_sol_types::SolCall;
+
+use crate::{
+    abi::multicall3_abi::Multicall3,
+    errors::{error::Error, ether_error::EtherError},
+};
+
+/*
+    Per-call outcome, reverts keep the return data so `revert_reason` works as for `eth_call`.
+*/
+pub type MulticallResult<R> = Result<R, EtherError>;
+
+#[derive(Clone, Debug)]
+pub struct MulticallCall<C> {
+    pub target: Address,
+    pub call: C,
+    pub allow_failure: bool,
+}
+
+impl<C: SolCall> MulticallCall<C> {
+    pub fn new(target: Address, call: C) -> Self {
+        Self {
+            target,
+            call,
+            allow_failure: true,
+        }
+    }
+
+    /*
+        With `aggregate3` the whole batch reverts when this call fails.
+    */
+    pub fn require_success(mut self) -> Self {
+        self.allow_failure = false;
+        self
+    }
+
+    fn decode_result(&self, result: &Multicall3::Result) -> MulticallResult<C::Return> {
+        if !result.success {
+            return Err(EtherError {
+                method: C::SIGNATURE.to_string(),
+                message: String::from("execution reverted"),
+                data: Some(serde_json::Value::String(hex::encode_prefixed(
+                    &result.returnData,
+                ))),
+                ..Default::default()
+            });
+        }
+
+        C::abi_decode_returns(&result.returnData).map_err(|e| EtherError {
+            method: C::SIGNATURE.to_string(),
+            message: e.to_string(),
+            ..Default::default()
+        })
+    }
+}
+
+/*
+    Calls batched into one `eth_call`: a single `MulticallCall`, a `Vec` of same typed calls
+    or a tuple mixing both, decoded into the matching shape of `MulticallResult`s.
+*/
+pub trait MulticallCalls {
+    type Returns;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn encode(&self) -> Result<Vec<Multicall3::Call3>, Error>;
+
+    fn decode(&self, results: &[Multicall3::Result]) -> Self::Returns;
+}
+
+impl<C: SolCall> MulticallCalls for MulticallCall<C> {
+    type Returns = MulticallResult<C::Return>;
+
+    fn len(&self) -> usize {
+        1
+    }
+
+    fn encode(&self) -> Result<Vec<Multicall3::Call3>, Error> {
+        Ok(vec![Multicall3::Call3 {
+            target: self.target,
+            allowFailure: self.allow_failure,
+            callData: Bytes::from(self.call.abi_encode()),
+        }])
+    }
+
+    fn decode(&self, results: &[Multicall3::Result]) -> Self::Returns {
+        self.decode_result(&results[0])
+    }
+}
+
+impl<C: SolCall> MulticallCalls for Vec<MulticallCall<C>> {
+    type Returns = Vec<MulticallResult<C::Return>>;
+
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn encode(&self) -> Result<Vec<Multicall3::Call3>, Error> {
+        let mut calls = Vec::with_capacity(self.as_slice().len());
+
+        for call in self.iter() {
+            calls.extend(call.encode()?);
+        }
+
+        Ok(calls)
+    }
+
+    fn decode(&self, results: &[Multicall3::Result]) -> Self::Returns {
+        self.iter()
+            .zip(results)
+            .map(|(call, result)| call.decode_result(result))
+            .collect()
+    }
+}
+
+macro_rules! impl_multicall_tuple {
+    ($($name:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$($name: MulticallCalls),+> MulticallCalls for ($($name,)+) {
+            type Returns = ($($name::Returns,)+);
+
+            fn len(&self) -> usize {
+                let ($($name,)+) = self;
+
+                0 $(+ $name.len())+
+            }
+
+            fn encode(&self) -> Result<Vec<Multicall3::Call3>, Error> {
+                let ($($name,)+) = self;
+                let mut calls = Vec::with_capacity(self.len());
+
+                $(calls.extend($name.encode()?);)+
+
+                Ok(calls)
+            }
+
+            #[allow(unused_assignments)]
+            fn decode(&self, results: &[Multicall3::Result]) -> Self::Returns {
+                let ($($name,)+) = self;
+                let mut offset = 0;
+
+                ($({
+                    let end = offset + $name.len();
+                    let decoded = $name.decode(&results[offset..end]);
+
+                    offset = end;
+                    decoded
+                },)+)
+            }
+        }
+    };
+}
+
+impl_multicall_tuple!(A);
+impl_multicall_tuple!(A, B);
+impl_multicall_tuple!(A, B, C);
+impl_multicall_tuple!(A, B, C, D);
+impl_multicall_tuple!(A, B, C, D, E);
+impl_multicall_tuple!(A, B, C, D, E, F);
+impl_multicall_tuple!(A, B, C, D, E, F, G);
+impl_multicall_tuple!(A, B, C, D, E, F, G, H);
@@ -0,0 +1,36 @@
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum NftStandard {
+    #[default]
+    Unknown,
+    Erc721,
+    Erc1155,
+}
+
+/*
+    OpenSea metadata standard, unknown fields are ignored.
+*/
+#[derive(Default, Clone, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct NftAttribute {
+    pub trait_type: Option<String>,
+    pub display_type: Option<String>,
+    pub value: serde_json::Value,
+}
+
+#[derive(Default, Clone, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct NftMetadata {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub image_data: Option<String>,
+    pub external_url: Option<String>,
+    pub animation_url: Option<String>,
+    pub background_color: Option<String>,
+    pub youtube_url: Option<String>,
+    pub attributes: Vec<NftAttribute>,
+    pub properties: Option<serde_json::Value>,
+    // Image ready for `img` src: gateway URL or data URI, on-chain SVG included;
+    #[serde(skip_deserializing)]
+    pub image_src: Option<String>,
+}
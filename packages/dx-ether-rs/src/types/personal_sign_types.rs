@@ -0,0 +1,65 @@
+use alloy_primitives::{B256, Bytes, eip191_hash_message};
+
+/*
+    `personal_sign` payload, UTF-8 text or raw bytes such as a 32 bytes hash.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub enum EtherPersonalSignMessage {
+    Text(String),
+    Bytes(Bytes),
+}
+
+impl EtherPersonalSignMessage {
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Text(text) => text.as_bytes(),
+            Self::Bytes(bytes) => bytes,
+        }
+    }
+
+    /*
+        EIP-191 `"\x19Ethereum Signed Message:\n" + len(message) + message` digest.
+    */
+    pub fn digest(&self) -> B256 {
+        eip191_hash_message(self.as_bytes())
+    }
+}
+
+impl Default for EtherPersonalSignMessage {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl From<String> for EtherPersonalSignMessage {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<Bytes> for EtherPersonalSignMessage {
+    fn from(value: Bytes) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl From<Vec<u8>> for EtherPersonalSignMessage {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Bytes(value.into())
+    }
+}
+
+impl From<B256> for EtherPersonalSignMessage {
+    fn from(value: B256) -> Self {
+        Self::Bytes(Bytes::copy_from_slice(value.as_slice()))
+    }
+}
+
+/*
+    `personal_sign` signature together with the EIP-191 digest that was signed.
+*/
+#[derive(Default, Clone, PartialEq, Debug)]
+pub struct EtherPersonalSignature {
+    pub signature: String,
+    pub digest: B256,
+}
@@ -0,0 +1,129 @@
+use alloy_primitives::Address;
+
+/*
+    EIP-4361 message, timestamps are RFC 3339 strings as they appear in the signed text.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct SiweMessage {
+    pub scheme: Option<String>,
+    pub domain: String,
+    pub address: Address,
+    pub statement: Option<String>,
+    pub uri: String,
+    pub version: String,
+    pub chain_id: u64,
+    pub nonce: String,
+    pub issued_at: String,
+    pub expiration_time: Option<String>,
+    pub not_before: Option<String>,
+    pub request_id: Option<String>,
+    pub resources: Vec<String>,
+}
+
+impl SiweMessage {
+    pub fn new(domain: &str, address: &Address, uri: &str, chain_id: u64) -> Self {
+        Self {
+            scheme: None,
+            domain: domain.to_string(),
+            address: *address,
+            statement: None,
+            uri: uri.to_string(),
+            version: String::from("1"),
+            chain_id,
+            nonce: String::new(),
+            issued_at: String::new(),
+            expiration_time: None,
+            not_before: None,
+            request_id: None,
+            resources: Vec::new(),
+        }
+    }
+
+    pub fn scheme(mut self, scheme: &str) -> Self {
+        self.scheme = Some(scheme.to_string());
+        self
+    }
+
+    pub fn statement(mut self, statement: &str) -> Self {
+        self.statement = Some(statement.to_string());
+        self
+    }
+
+    pub fn nonce(mut self, nonce: &str) -> Self {
+        self.nonce = nonce.to_string();
+        self
+    }
+
+    pub fn issued_at(mut self, issued_at: &str) -> Self {
+        self.issued_at = issued_at.to_string();
+        self
+    }
+
+    pub fn expiration_time(mut self, expiration_time: &str) -> Self {
+        self.expiration_time = Some(expiration_time.to_string());
+        self
+    }
+
+    pub fn not_before(mut self, not_before: &str) -> Self {
+        self.not_before = Some(not_before.to_string());
+        self
+    }
+
+    pub fn request_id(mut self, request_id: &str) -> Self {
+        self.request_id = Some(request_id.to_string());
+        self
+    }
+
+    pub fn resource(mut self, resource: &str) -> Self {
+        self.resources.push(resource.to_string());
+        self
+    }
+}
+
+/*
+    Checks applied on top of the message syntax, `time` is the unix time in seconds.
+    No `Default`: a zero time would let every expired message through.
+*/
+#[derive(Clone, PartialEq, Debug)]
+pub struct SiweVerifyOptions {
+    pub domain: Option<String>,
+    pub nonce: Option<String>,
+    pub chain_id: Option<u64>,
+    pub time: u64,
+}
+
+impl SiweVerifyOptions {
+    pub fn new(time: u64) -> Self {
+        Self {
+            domain: None,
+            nonce: None,
+            chain_id: None,
+            time,
+        }
+    }
+
+    pub fn domain(mut self, domain: &str) -> Self {
+        self.domain = Some(domain.to_string());
+        self
+    }
+
+    pub fn nonce(mut self, nonce: &str) -> Self {
+        self.nonce = Some(nonce.to_string());
+        self
+    }
+
+    pub fn chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+}
+
+/*
+    Signed EIP-4361 message, `text` and `signature` are what the backend verifies.
+*/
+#[derive(Default, Clone, PartialEq, Debug)]
+pub struct SiweSession {
+    pub message: Option<SiweMessage>,
+    pub text: String,
+    pub signature: String,
+}
@@ -0,0 +1,257 @@
+use alloy_primitives::{Address, B256, Bytes, U256, hex, keccak256};
+use alloy_sol_types::SolValue;
+use serde::Deserialize;
+
+use crate::{
+    abi::entry_point_abi::PackedUserOperation,
+    adapters::{
+        ether_adapter::{EtherLog, EtherTransactionReceipt},
+        ether_chain_adapter::ChainId,
+    },
+};
+
+/*
+    ERC-4337 v0.7 user operation in the bundler RPC shape, `pack` gives the EntryPoint struct.
+*/
+#[derive(Default, Clone, PartialEq, Debug)]
+pub struct UserOperation {
+    pub sender: Address,
+    // Upper 192 bits are the nonce key, lower 64 bits the sequence;
+    pub nonce: U256,
+    // Only for the first operation of a counterfactual account;
+    pub factory: Option<Address>,
+    pub factory_data: Bytes,
+    pub call_data: Bytes,
+    pub call_gas_limit: u128,
+    pub verification_gas_limit: u128,
+    pub pre_verification_gas: u128,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+    pub paymaster: Option<Address>,
+    pub paymaster_verification_gas_limit: u128,
+    pub paymaster_post_op_gas_limit: u128,
+    pub paymaster_data: Bytes,
+    pub signature: Bytes,
+}
+
+impl UserOperation {
+    pub fn new(sender: Address) -> Self {
+        Self {
+            sender,
+            ..Default::default()
+        }
+    }
+
+    pub fn nonce(mut self, nonce: U256) -> Self {
+        self.nonce = nonce;
+        self
+    }
+
+    pub fn factory(mut self, factory: Address, factory_data: Bytes) -> Self {
+        self.factory = Some(factory);
+        self.factory_data = factory_data;
+        self
+    }
+
+    /*
+        Account calldata, e.g. `execute(to, value, data)` of the smart account.
+    */
+    pub fn call_data(mut self, call_data: Bytes) -> Self {
+        self.call_data = call_data;
+        self
+    }
+
+    pub fn fees(mut self, max_fee_per_gas: u128, max_priority_fee_per_gas: u128) -> Self {
+        self.max_fee_per_gas = max_fee_per_gas;
+        self.max_priority_fee_per_gas = max_priority_fee_per_gas;
+        self
+    }
+
+    pub fn gas(mut self, gas: &EtherUserOperationGas) -> Self {
+        self.call_gas_limit = gas.call_gas_limit;
+        self.verification_gas_limit = gas.verification_gas_limit;
+        self.pre_verification_gas = gas.pre_verification_gas;
+        self.paymaster_verification_gas_limit = gas.paymaster_verification_gas_limit;
+        self.paymaster_post_op_gas_limit = gas.paymaster_post_op_gas_limit;
+        self
+    }
+
+    pub fn paymaster(mut self, paymaster: Address, paymaster_data: Bytes) -> Self {
+        self.paymaster = Some(paymaster);
+        self.paymaster_data = paymaster_data;
+        self
+    }
+
+    /*
+        Required by the bundler methods, a dummy one of the right shape while estimating
+        (e.g. 65 bytes for ECDSA owned accounts).
+    */
+    pub fn signature(mut self, signature: Bytes) -> Self {
+        self.signature = signature;
+        self
+    }
+
+    pub fn pack(&self) -> PackedUserOperation {
+        let init_code = match self.factory {
+            Some(r) => [r.as_slice(), &self.factory_data].concat(),
+            None => Vec::new(),
+        };
+
+        let paymaster_and_data = match self.paymaster {
+            Some(r) => [
+                r.as_slice(),
+                &self.paymaster_verification_gas_limit.to_be_bytes(),
+                &self.paymaster_post_op_gas_limit.to_be_bytes(),
+                &self.paymaster_data,
+            ]
+            .concat(),
+            None => Vec::new(),
+        };
+
+        PackedUserOperation {
+            sender: self.sender,
+            nonce: self.nonce,
+            initCode: init_code.into(),
+            callData: self.call_data.clone(),
+            accountGasLimits: pack_gas(self.verification_gas_limit, self.call_gas_limit),
+            preVerificationGas: U256::from(self.pre_verification_gas),
+            gasFees: pack_gas(self.max_priority_fee_per_gas, self.max_fee_per_gas),
+            paymasterAndData: paymaster_and_data.into(),
+            signature: self.signature.clone(),
+        }
+    }
+
+    /*
+        `userOpHash` as computed by `EntryPoint.getUserOpHash`, the signature is not part of it.
+    */
+    pub fn hash(&self, entry_point: Address, chain_id: ChainId) -> B256 {
+        let packed = self.pack();
+
+        let inner = keccak256(
+            (
+                packed.sender,
+                packed.nonce,
+                keccak256(&packed.initCode),
+                keccak256(&packed.callData),
+                packed.accountGasLimits,
+                packed.preVerificationGas,
+                packed.gasFees,
+                keccak256(&packed.paymasterAndData),
+            )
+                .abi_encode(),
+        );
+
+        keccak256((inner, entry_point, U256::from(chain_id.0)).abi_encode())
+    }
+
+    /*
+        JSON of `eth_sendUserOperation` and `eth_estimateUserOperationGas`.
+    */
+    pub fn to_rpc(&self) -> serde_json::Value {
+        let quantity = |value: u128| format!("0x{:x}", value);
+
+        let mut value = serde_json::json!({
+            "sender": self.sender.to_checksum(None),
+            "nonce": format!("0x{:x}", self.nonce),
+            "callData": hex::encode_prefixed(&self.call_data),
+            "callGasLimit": quantity(self.call_gas_limit),
+            "verificationGasLimit": quantity(self.verification_gas_limit),
+            "preVerificationGas": quantity(self.pre_verification_gas),
+            "maxFeePerGas": quantity(self.max_fee_per_gas),
+            "maxPriorityFeePerGas": quantity(self.max_priority_fee_per_gas),
+            "signature": hex::encode_prefixed(&self.signature),
+        });
+
+        if let Some(factory) = self.factory {
+            value["factory"] = serde_json::Value::from(factory.to_checksum(None));
+            value["factoryData"] =
+                serde_json::Value::from(hex::encode_prefixed(&self.factory_data));
+        }
+
+        if let Some(paymaster) = self.paymaster {
+            value["paymaster"] = serde_json::Value::from(paymaster.to_checksum(None));
+            value["paymasterVerificationGasLimit"] =
+                serde_json::Value::from(quantity(self.paymaster_verification_gas_limit));
+            value["paymasterPostOpGasLimit"] =
+                serde_json::Value::from(quantity(self.paymaster_post_op_gas_limit));
+            value["paymasterData"] =
+                serde_json::Value::from(hex::encode_prefixed(&self.paymaster_data));
+        }
+
+        value
+    }
+}
+
+fn pack_gas(high: u128, low: u128) -> B256 {
+    let mut packed = [0u8; 32];
+
+    packed[..16].copy_from_slice(&high.to_be_bytes());
+    packed[16..].copy_from_slice(&low.to_be_bytes());
+
+    B256::from(packed)
+}
+
+/*
+    `eth_estimateUserOperationGas` result, paymaster limits are zero without a paymaster.
+*/
+#[derive(Default, Clone, PartialEq, Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EtherUserOperationGas {
+    #[serde(deserialize_with = "deserialize_quantity")]
+    pub pre_verification_gas: u128,
+    #[serde(deserialize_with = "deserialize_quantity")]
+    pub verification_gas_limit: u128,
+    #[serde(deserialize_with = "deserialize_quantity")]
+    pub call_gas_limit: u128,
+    #[serde(default, deserialize_with = "deserialize_quantity")]
+    pub paymaster_verification_gas_limit: u128,
+    #[serde(default, deserialize_with = "deserialize_quantity")]
+    pub paymaster_post_op_gas_limit: u128,
+}
+
+fn deserialize_quantity<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<u128, D::Error> {
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Null => Ok(0),
+        serde_json::Value::Number(r) => r
+            .as_u64()
+            .map(u128::from)
+            .ok_or_else(|| serde::de::Error::custom("invalid quantity")),
+        serde_json::Value::String(r) => match r.strip_prefix("0x") {
+            Some(i) => u128::from_str_radix(i, 16),
+            None => r.parse::<u128>(),
+        }
+        .map_err(|_| serde::de::Error::custom(format!("invalid quantity {}", r))),
+        r => Err(serde::de::Error::custom(format!("invalid quantity {}", r))),
+    }
+}
+
+/*
+    `eth_getUserOperationReceipt` result, `receipt` is the bundle transaction receipt.
+*/
+#[derive(Default, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EtherUserOperationReceipt {
+    pub user_op_hash: String,
+    #[serde(default)]
+    pub entry_point: String,
+    #[serde(default)]
+    pub sender: String,
+    #[serde(default)]
+    pub nonce: String,
+    #[serde(default)]
+    pub paymaster: Option<String>,
+    #[serde(default)]
+    pub actual_gas_cost: String,
+    #[serde(default)]
+    pub actual_gas_used: String,
+    pub success: bool,
+    // Revert data of the account call when `success` is false;
+    #[serde(default)]
+    pub reason: Option<String>,
+    #[serde(default)]
+    pub logs: Vec<EtherLog>,
+    #[serde(default)]
+    pub receipt: EtherTransactionReceipt,
+}
@@ -1,5 +1,3 @@
-pub mod abi {
-    use alloy_sol_macro::sol;
+use alloy_sol_macro::sol;
 
-    sol!(WETH_ABI, "src/abi/weth_abi.json");
-}
+sol!(WETH_ABI, "src/abi/weth_abi.json");
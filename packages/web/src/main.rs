@@ -1,73 +1,90 @@
 mod abi;
 mod providers;
 
-use std::str::FromStr;
-
 use crate::{
-    abi::abi::WETH_ABI,
+    abi::WETH_ABI,
     providers::{
         ether_account_provider::{EtherAccountContext, EtherAccountProvider},
         ether_chain_id_provider::{EtherChainIdContext, EtherChainIdProvider},
     },
 };
 
-use alloy_core::{
-    hex,
-    primitives::{Address, U256},
-};
-use alloy_sol_types::SolCall;
+use alloy_core::primitives::Address;
 use dioxus::{logger::tracing, prelude::*};
 use dx_ether_rs::{
-    adapters::ether_hash_adapter::EtherHashAdapter,
+    adapters::{
+        ether_address_adapter::EtherAddressAdapter,
+        ether_chain_adapter::{self, explorer_tx_url, known_chain},
+        ether_units_adapter::Ether,
+    },
     constants::scripts_constants::DX_EVAL_PROVIDER_SCRIPT,
     hooks::{
+        use_contract_read::use_contract_read,
+        use_contract_write::use_contract_write,
+        use_erc20_balance::use_erc20_balance,
         use_ether_accounts::use_ether_accounts,
         use_ether_block_number::use_ether_block_number,
-        use_ether_call::use_ether_call,
         use_ether_gas_price::use_ether_gas_price,
         use_ether_get_balance::use_ether_get_balance,
+        use_ether_get_block_by_number::use_ether_get_block_by_number,
         use_ether_get_transaction_receipt::use_ether_get_transaction_receipt,
         use_ether_initialize::use_ether_initialize,
-        use_ether_personal_sign::use_ether_personal_sign,
+        use_ether_personal_sign::{UseEtherPersonalSign, use_ether_personal_sign},
         use_ether_request_accounts::use_ether_request_accounts,
+        use_ether_request_permissions::use_ether_request_permissions,
+        use_ether_revoke_permissions::use_ether_revoke_permissions,
         use_ether_send_transaction::{UseEtherSendTransaction, use_ether_send_transaction},
         use_ether_switch_chain_id::use_ether_switch_chain_id,
     },
-    ports::ether_hash_port::EtherHashPort,
+    ports::ether_address_port::EtherAddressPort,
+    types::contract_types::ContractTransactionOptions,
 };
 
+// Chain the wallet is connected to, provided by `EtherChainIdProvider`;
+fn use_connected_chain_id() -> Option<ether_chain_adapter::ChainId> {
+    let chain_id = use_context::<Signal<EtherChainIdContext>>();
+
+    chain_id().0().parse().ok()
+}
+
+// WETH ERC20 of the connected chain from the chain registry;
+fn use_weth_erc20_address() -> Option<Address> {
+    use_connected_chain_id()
+        .and_then(known_chain)
+        .and_then(|r| r.wrapped_native)
+        .and_then(|r| r.parse::<Address>().ok())
+}
+
 #[component]
 fn SendSmartContractTransaction() -> Element {
-    let weth_erc20_address = "0x7b79995e5f793a07bc00c21412e50ecae098e7f9";
+    let weth_erc20_address = use_weth_erc20_address();
 
     let account = use_context::<Signal<EtherAccountContext>>();
 
     let mut activated = use_signal(|| false);
     let mut amount = use_signal(|| String::from("0.00001"));
 
-    let address = Address::from_str(&account().0()).unwrap();
-
-    let amount_wei = U256::from(amount().parse::<f64>().unwrap() * 10_f64.powi(18));
-
-    let approve_calldata = WETH_ABI::approveCall {
-        guy: address,
-        wad: amount_wei,
-    }
-    .abi_encode();
-
-    let approve_calldata_hex = EtherHashAdapter::new()
-        .encode_to_string(&approve_calldata)
-        .unwrap();
-
-    let (trx_hash, trx_error, trx_loading) = use_ether_send_transaction(&UseEtherSendTransaction {
-        from: account().0(),
-        to: weth_erc20_address.to_string(),
-        value: None,
-        calldata: Some(approve_calldata_hex),
-        gas: None,
-        gas_price: None,
-        activated: Some(activated),
-    });
+    let address = account().0();
+
+    // Invalid input keeps the write disabled and shows the parse error instead of sending 0;
+    let (amount_wei, amount_error) = match Ether::parse(&amount()) {
+        Ok(r) => (Some(r.wei()), String::new()),
+        Err(e) => (None, e.to_string()),
+    };
+
+    let (trx_hash, trx_error, trx_loading, trx_ether_error) =
+        use_contract_write::<WETH_ABI::WETH_ABICalls, _>(
+            weth_erc20_address.unwrap_or_default(),
+            WETH_ABI::approveCall {
+                guy: address,
+                wad: amount_wei.unwrap_or_default(),
+            },
+            &ContractTransactionOptions {
+                from: account().0(),
+                ..Default::default()
+            },
+            Some(activated),
+        );
 
     use_effect(move || {
         let trx_error = trx_error();
@@ -78,10 +95,23 @@ fn SendSmartContractTransaction() -> Element {
         }
     });
 
+    // Decoded `Error(string)` / `Panic(uint256)` revert of the failed write, if any;
+    let trx_revert_reason = trx_ether_error()
+        .and_then(|r| r.revert_reason())
+        .unwrap_or_default();
+
     let send_transaction_callback = use_callback(move |_: ()| {
-        activated.set(!activated());
+        if amount_wei.is_some() && weth_erc20_address.is_some() {
+            activated.set(!activated());
+        }
     });
 
+    if weth_erc20_address.is_none() {
+        return rsx! {
+            div { "Wrap ether: no WETH on this chain" }
+        };
+    }
+
     rsx! {
         div {
             "Wrap ether: "
@@ -89,11 +119,20 @@ fn SendSmartContractTransaction() -> Element {
                 "Hash: "
                 span { {trx_hash} }
             }
+            div {
+                "Revert reason: "
+                span { {trx_revert_reason} }
+            }
             div {
                 input { value: amount(), oninput: move |e| amount.set(e.value()) }
+                span { {amount_error} }
             }
             div {
-                button { onclick: move |_| send_transaction_callback(()), "Wrap" }
+                button {
+                    disabled: amount_wei.is_none(),
+                    onclick: move |_| send_transaction_callback(()),
+                    "Wrap"
+                }
             }
         }
     }
@@ -103,18 +142,30 @@ fn SendSmartContractTransaction() -> Element {
 fn SendTransferTransaction() -> Element {
     let account = use_context::<Signal<EtherAccountContext>>();
 
+    let chain_id = use_connected_chain_id();
+
     let mut activated = use_signal(|| false);
     let mut recipient = use_signal(|| String::from("0x..."));
 
-    let (trx_hash, trx_error, trx_loading) = use_ether_send_transaction(&UseEtherSendTransaction {
-        from: account().0(),
-        to: recipient(),
-        value: Some("100000000000000".to_string()), // 0.0001 ETH;
-        calldata: None,
-        gas: None,
-        gas_price: None,
-        activated: Some(activated),
-    });
+    // EIP-55, or EIP-1191 on chains with chain specific checksums;
+    let ether_address = chain_id
+        .map(EtherAddressAdapter::for_chain)
+        .unwrap_or_default();
+    let (recipient_address, recipient_error) = match ether_address.parse_address(&recipient()) {
+        Ok(r) => (Some(r), String::new()),
+        Err(e) => (None, e.to_string()),
+    };
+
+    let (trx_hash, trx_error, trx_loading, _) =
+        use_ether_send_transaction(&UseEtherSendTransaction {
+            from: account().0(),
+            to: recipient_address.unwrap_or_default(),
+            value: Some("100000000000000".to_string()), // 0.0001 ETH;
+            calldata: None,
+            gas: None,
+            gas_price: None,
+            activated: Some(activated),
+        });
 
     use_effect(move || {
         let trx_error = trx_error();
@@ -126,7 +177,9 @@ fn SendTransferTransaction() -> Element {
     });
 
     let send_transaction_callback = use_callback(move |_: ()| {
-        activated.set(!activated());
+        if recipient_address.is_some() {
+            activated.set(!activated());
+        }
     });
 
     rsx! {
@@ -141,9 +194,14 @@ fn SendTransferTransaction() -> Element {
                     value: recipient(),
                     oninput: move |e| recipient.set(e.value()),
                 }
+                span { {recipient_error} }
             }
             div {
-                button { onclick: move |_| send_transaction_callback(()), "Send" }
+                button {
+                    disabled: recipient_address.is_none(),
+                    onclick: move |_| send_transaction_callback(()),
+                    "Send"
+                }
             }
         }
     }
@@ -154,6 +212,8 @@ fn TransactionReceiptView() -> Element {
     // WETH ERC20 deposit transaction hash Sepolia;
     let transaction_hash = "0x98610b3065f20be137e2d4b8ba33b1ceb8ae02689f08ff2c5ffd7bac74e9c434";
 
+    let chain_id = use_connected_chain_id();
+
     let (receipt, receipt_error, receipt_loading) =
         use_ether_get_transaction_receipt(transaction_hash);
 
@@ -174,6 +234,9 @@ fn TransactionReceiptView() -> Element {
 
     let receipt = receipt.read();
 
+    let events = receipt.decode_logs::<WETH_ABI::WETH_ABIEvents>();
+    let deposits = receipt.filter_logs::<WETH_ABI::Deposit>();
+
     rsx! {
         div {
             "Transaction receipt: "
@@ -191,7 +254,18 @@ fn TransactionReceiptView() -> Element {
                 li { {format!("Logs bloom: {}", receipt.logs_bloom)} }
                 li { {format!("Status: {}", receipt.status)} }
                 li { {format!("Hash: {}", receipt.transaction_hash)} }
+                if let Some(url) = chain_id
+                    .and_then(|r| explorer_tx_url(r, &receipt.transaction_hash.to_string()))
+                {
+                    li {
+                        a { href: url, target: "_blank", "View on explorer" }
+                    }
+                }
                 li { {format!("Index: {}", receipt.transaction_index)} }
+                li { {format!("Logs: {}, WETH events: {}", receipt.logs.len(), events.len())} }
+                {deposits.iter().map(|i| rsx! {
+                    li { {format!("Deposit: {} -> {} wad", i.dst, i.wad)} }
+                })}
             }
         }
     }
@@ -199,46 +273,21 @@ fn TransactionReceiptView() -> Element {
 
 #[component]
 fn InteractContract() -> Element {
-    // WETH ERC20 Sepolia;
-    let weth_erc20_address = "0x7b79995e5f793a07bc00c21412e50ecae098e7f9";
-    let calldata = WETH_ABI::nameCall {}.abi_encode();
-    let hash_calldata = format!("0x{}", hex::encode(calldata));
-
-    let mut name = use_signal(String::new);
+    let weth_erc20_address = use_weth_erc20_address();
 
-    let (call, call_error, call_loading) = use_ether_call(
-        weth_erc20_address,
-        &hash_calldata,
+    let (name, name_error, name_loading, _) = use_contract_read::<WETH_ABI::WETH_ABICalls, _>(
+        weth_erc20_address.unwrap_or_default(),
+        WETH_ABI::nameCall {},
         None,
-        Some(Signal::new(true)),
+        Some(Signal::new(weth_erc20_address.is_some())),
     );
 
     use_effect(move || {
-        let call = call();
-        let call_error = call_error();
-        let call_loading = call_loading();
+        let name_error = name_error();
+        let name_loading = name_loading();
 
-        if !call_error.is_empty() && !call_loading {
-            tracing::error!(call_error);
-        }
-
-        if !call.is_empty() {
-            let name_bytes = EtherHashAdapter::new()
-                .decode_to_string(&call)
-                .unwrap_or(String::new());
-
-            if name_bytes.is_empty() {
-                return;
-            }
-
-            let decoded = WETH_ABI::nameCall::abi_decode_returns(&name_bytes.as_bytes())
-                .unwrap_or(String::new());
-
-            if decoded.is_empty() {
-                return;
-            }
-
-            name.set(decoded);
+        if !name_error.is_empty() && !name_loading {
+            tracing::error!(name_error);
         }
     });
 
@@ -246,12 +295,12 @@ fn InteractContract() -> Element {
         div {
             "ERC20 call name(): "
             {
-                match name().is_empty() {
-                    true => rsx! {
-                        span { "name is empty" }
+                match name() {
+                    Some(r) => rsx! {
+                        span { {r} }
                     },
-                    false => rsx! {
-                        span { {name} }
+                    None => rsx! {
+                        span { "name is empty" }
                     },
                 }
             }
@@ -307,12 +356,41 @@ fn BlockNumber() -> Element {
     }
 }
 
+#[component]
+fn LatestBlock() -> Element {
+    let (block, block_error, block_loading) =
+        use_ether_get_block_by_number("latest", false, Some(Signal::new(true)));
+
+    use_effect(move || {
+        let block_error = block_error();
+        let block_loading = block_loading();
+
+        if !block_error.is_empty() && !block_loading {
+            tracing::error!(block_error);
+        }
+    });
+
+    match block() {
+        None => rsx! {},
+        Some(block) => rsx! {
+            div {
+                "Latest block: "
+                ul {
+                    li { {format!("Hash: {}", block.hash)} }
+                    li { {format!("Timestamp: {}", block.block_timestamp().unwrap_or_default())} }
+                    li { {format!("Transactions: {}", block.transactions.hashes().len())} }
+                }
+            }
+        },
+    }
+}
+
 #[component]
 fn Balance() -> Element {
     let account = use_context::<Signal<EtherAccountContext>>();
 
     let (balance, balance_error, balance_loading) =
-        use_ether_get_balance(&account().0(), None, Some(Signal::new(true)));
+        use_ether_get_balance(account().0(), None, Some(Signal::new(true)));
 
     use_effect(move || {
         let balance_error = balance_error();
@@ -334,15 +412,44 @@ fn Balance() -> Element {
     }
 }
 
+#[component]
+fn Erc20Balance() -> Element {
+    let weth_erc20_address = use_weth_erc20_address();
+
+    let account = use_context::<Signal<EtherAccountContext>>();
+
+    let (balance, balance_error, balance_loading) = use_erc20_balance(
+        weth_erc20_address.unwrap_or_default(),
+        account().0(),
+        Some(Signal::new(weth_erc20_address.is_some())),
+    );
+
+    use_effect(move || {
+        let balance_error = balance_error();
+        let balance_loading = balance_loading();
+
+        if !balance_error.is_empty() && !balance_loading {
+            tracing::error!(balance_error);
+        }
+    });
+
+    rsx! {
+        div {
+            "WETH balance: "
+            span { {balance().to_string()} }
+        }
+    }
+}
+
 #[component]
 fn SwitchWalletChainId() -> Element {
     let chain_id = use_context::<Signal<EtherChainIdContext>>();
 
     let mut activated = use_signal(|| false);
-    let mut input_chain_id = use_signal(|| String::from("11155111"));
+    let mut input_chain_id = use_signal(|| chain_id().0());
 
     let (chain_id_error, chain_id_loading) =
-        use_ether_switch_chain_id(&input_chain_id(), Some(activated));
+        use_ether_switch_chain_id(&input_chain_id(), None, Some(activated));
 
     use_effect(move || {
         let chain_id_error = chain_id_error();
@@ -380,11 +487,18 @@ fn SwitchWalletChainId() -> Element {
 fn ChainId() -> Element {
     let chain_id = use_context::<Signal<EtherChainIdContext>>();
     let chain_id = chain_id().0();
+    let chain_name = chain_id
+        .parse::<ether_chain_adapter::ChainId>()
+        .ok()
+        .and_then(known_chain)
+        .map(|r| r.name)
+        .unwrap_or_default();
 
     rsx! {
         div {
             "Chain id: "
             span { {chain_id} }
+            span { {format!(" {}", chain_name)} }
         }
     }
 }
@@ -410,7 +524,7 @@ fn Accounts() -> Element {
             div {
                 "Accounts: "
                 {items.iter().map(|i| rsx! {
-                    span { {i.clone()} }
+                    span { {i.to_string()} }
                 })}
             }
         },
@@ -419,12 +533,15 @@ fn Accounts() -> Element {
 
 #[component]
 fn PersonalSign() -> Element {
+    let account = use_context::<Signal<EtherAccountContext>>();
+
     let mut activated = use_signal(|| false);
-    let (signature, signature_error, signature_loading) = use_ether_personal_sign(
-        "Hello",
-        "0x57ca7f8e277696f79f913d853E809B18805807Cc",
-        Some(activated),
-    );
+    let (signature, signature_error, signature_loading) =
+        use_ether_personal_sign(&UseEtherPersonalSign {
+            address: account().0(),
+            message: "Hello\nfrom dx-ether-rs".into(),
+            activated: Some(activated),
+        });
 
     use_effect(move || {
         let signature_error = signature_error();
@@ -444,7 +561,11 @@ fn PersonalSign() -> Element {
             button { onclick: move |_| request(()), "Personal Sign" }
             div {
                 "Signature: "
-                span { {signature} }
+                span { {signature().signature} }
+            }
+            div {
+                "Digest: "
+                span { {signature().digest.to_string()} }
             }
         }
     }
@@ -475,6 +596,66 @@ fn ConnectWallet() -> Element {
     }
 }
 
+#[component]
+fn SwitchAccount() -> Element {
+    let mut activated = use_signal(|| false);
+    let (permissions, permissions_error, permissions_loading) =
+        use_ether_request_permissions(&["eth_accounts"], Some(activated));
+
+    use_effect(move || {
+        let permissions_error = permissions_error();
+        let permissions_loading = permissions_loading();
+
+        if !permissions_error.is_empty() && !permissions_loading {
+            tracing::error!(permissions_error);
+        }
+    });
+
+    let switch_account = use_callback(move |_: ()| {
+        activated.set(!activated());
+    });
+
+    let accounts = permissions()
+        .iter()
+        .flat_map(|r| r.accounts())
+        .collect::<Vec<_>>();
+
+    rsx! {
+        div {
+            button { onclick: move |_| switch_account(()), "Switch Account" }
+            {accounts.iter().map(|i| rsx! {
+                span { {i.to_string()} }
+            })}
+        }
+    }
+}
+
+#[component]
+fn Disconnect() -> Element {
+    let mut activated = use_signal(|| false);
+    let (revoke_error, revoke_loading) =
+        use_ether_revoke_permissions(&["eth_accounts"], Some(activated));
+
+    use_effect(move || {
+        let revoke_error = revoke_error();
+        let revoke_loading = revoke_loading();
+
+        if !revoke_error.is_empty() && !revoke_loading {
+            tracing::error!(revoke_error);
+        }
+    });
+
+    let disconnect = use_callback(move |_: ()| {
+        activated.set(!activated());
+    });
+
+    rsx! {
+        div {
+            button { onclick: move |_| disconnect(()), "Disconnect" }
+        }
+    }
+}
+
 #[component]
 fn Main() -> Element {
     let (ether_error, ether_loading) = use_ether_initialize();
@@ -496,20 +677,26 @@ fn Main() -> Element {
     rsx! {
         div {
             ConnectWallet {}
-            PersonalSign {}
+            SwitchAccount {}
+            Disconnect {}
             Accounts {}
-            EtherAccountProvider { Balance {} }
-            InteractContract {}
             EtherChainIdProvider {
+                EtherAccountProvider {
+                    PersonalSign {}
+                    Balance {}
+                    Erc20Balance {}
+                }
+                InteractContract {}
                 ChainId {}
                 SwitchWalletChainId {}
-            }
-            BlockNumber {}
-            GasPrice {}
-            TransactionReceiptView {}
-            EtherAccountProvider {
-                SendTransferTransaction {}
-                SendSmartContractTransaction {}
+                BlockNumber {}
+                LatestBlock {}
+                GasPrice {}
+                TransactionReceiptView {}
+                EtherAccountProvider {
+                    SendTransferTransaction {}
+                    SendSmartContractTransaction {}
+                }
             }
         }
     }
@@ -1,8 +1,9 @@
+use alloy_core::primitives::Address;
 use dioxus::{logger::tracing, prelude::*};
 use dx_ether_rs::hooks::use_ether_accounts::use_ether_accounts;
 
 #[derive(Clone)]
-pub struct EtherAccountContext(pub Signal<String>);
+pub struct EtherAccountContext(pub Signal<Address>);
 
 #[component]
 pub fn EtherAccountProvider(children: Element) -> Element {
@@ -24,7 +25,7 @@ pub fn EtherAccountProvider(children: Element) -> Element {
     }
 
     let account = match accounts().first() {
-        Some(r) => r.to_string(),
+        Some(r) => *r,
         _ => {
             return rsx! {
                 h1 { "Ether account not found." }